@@ -0,0 +1,238 @@
+use super::{
+    derive_validations, form_name, is_option, vec_item, DefaultValue, FieldKind, MulterAttribute,
+};
+use proc_macro2::TokenStream;
+use quote::quote;
+use std::collections::HashMap;
+use syn::{Data, DeriveInput, Fields, GenericParam};
+
+pub fn derive_from_multipart_async(mut input: DeriveInput) -> syn::Result<TokenStream> {
+    // impl<A: FromFieldAsync + Send> for #name
+    for generic in input.generics.params.iter_mut() {
+        if let GenericParam::Type(generic_ty) = generic {
+            generic_ty
+                .bounds
+                .push(syn::parse_str("::multer_derive::FromFieldAsync")?);
+            generic_ty
+                .bounds
+                .push(syn::parse_str("::std::marker::Send")?);
+        }
+    }
+
+    let name = &input.ident;
+    let container_attr = MulterAttribute::from_attributes(&input.attrs)?.unwrap_or_default();
+
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => return Err(named_struct_error(name)),
+        },
+        _ => return Err(named_struct_error(name)),
+    };
+
+    if container_attr.tag.is_some()
+        || container_attr.collect_errors
+        || container_attr.validate_fn.is_some()
+    {
+        return Err(syn::Error::new_spanned(
+            name,
+            "`FromMultipartAsync` only supports `rename_all` and `default` on structs",
+        ));
+    }
+
+    let container_default = container_attr.default.as_ref();
+
+    let mut slots = Vec::new();
+    let mut arms = Vec::new();
+    let mut required = Vec::new();
+    let mut values = Vec::new();
+    let mut form_names = HashMap::new();
+
+    for f in fields {
+        let field_name = f.ident.as_ref().unwrap();
+        let attr = MulterAttribute::from_attributes(&f.attrs)?;
+        let field_name_str = form_name(&field_name.to_string(), attr.as_ref(), &container_attr);
+        let attr = attr.unwrap_or_default();
+        let field_ty = &f.ty;
+
+        let kind = attr.field_kind(field_name)?;
+        if !matches!(kind, FieldKind::Value | FieldKind::Skip)
+            || attr.tag.is_some()
+            || attr.notation.is_some()
+            || attr.collect_errors
+            || attr.validate_fn.is_some()
+            || attr.limit.is_some()
+        {
+            return Err(syn::Error::new_spanned(
+                field_name,
+                "`FromMultipartAsync` only supports `rename`, `with`, `default`, `skip` and `validate(...)` on fields",
+            ));
+        }
+
+        let fallback = match (&attr.default, container_default) {
+            (Some(default), _) => Some(default.to_expr()?),
+            (None, Some(_)) => Some(quote! { __default.#field_name }),
+            (None, None) => None,
+        };
+
+        if kind == FieldKind::Skip {
+            let value = fallback.unwrap_or(DefaultValue::Default.to_expr()?);
+            values.push(quote! { let #field_name = #value; });
+            continue;
+        }
+
+        if let Some(other) = form_names.insert(field_name_str.clone(), field_name) {
+            return Err(syn::Error::new_spanned(
+                field_name,
+                format!("form field `{field_name_str}` is already used by field `{other}`"),
+            ));
+        }
+
+        // Each field with the name of a `Vec<T>` is pushed, other fields can only be sent once
+        let item_ty = vec_item(field_ty).filter(|_| attr.with.is_none());
+        let parsed_ty = item_ty.unwrap_or(field_ty);
+
+        let call = match &attr.with {
+            Some(with) => {
+                let with_fn = syn::parse_str::<syn::Path>(with)?;
+                quote! { #with_fn(__field) }
+            }
+            None => quote! {
+                <#parsed_ty as ::multer_derive::FromFieldAsync>::from_field_async(__field)
+            },
+        };
+
+        let parse = quote! {
+            let __index = __field.index();
+            let __parsed: #parsed_ty = #call
+                .await
+                .map_err(|err| err.for_field(#field_name_str, #field_name_str, Some(__index)))?;
+        };
+
+        match item_ty {
+            Some(_) => {
+                slots.push(quote! { let mut #field_name = ::std::vec::Vec::new(); });
+                arms.push(quote! {
+                    #field_name_str => {
+                        #parse
+                        #field_name.push(__parsed);
+                    }
+                });
+            }
+            None => {
+                slots.push(quote! { let mut #field_name = ::std::option::Option::None; });
+                arms.push(quote! {
+                    #field_name_str => {
+                        // Like `FromMultipart`, only the first field with the name is parsed
+                        if #field_name.is_some() {
+                            continue;
+                        }
+
+                        #parse
+                        #field_name = Some(__parsed);
+                    }
+                });
+            }
+        }
+
+        // Missing fields are reported together once the whole form was read
+        let value = match (item_ty, fallback) {
+            (Some(_), _) => quote! { #field_name },
+            (None, Some(fallback)) => quote! {
+                match #field_name {
+                    Some(value) => value,
+                    None => #fallback,
+                }
+            },
+            (None, None) if is_option(field_ty) => quote! { #field_name.unwrap_or_default() },
+            (None, None) => {
+                required.push(quote! {
+                    if #field_name.is_none() {
+                        __errors.push(
+                            ::multer_derive::FieldError::new(::multer_derive::FieldErrorKind::Missing)
+                                .with_name(#field_name_str)
+                                .with_path(#field_name_str),
+                        );
+                    }
+                });
+
+                quote! { #field_name.unwrap() }
+            }
+        };
+
+        let value = match attr.validate.as_slice() {
+            [] => value,
+            validations => {
+                derive_validations(value, validations, field_ty, &field_name_str, false)?
+            }
+        };
+
+        values.push(quote! {
+            let #field_name = {
+                let __field_path = #field_name_str;
+                #value
+            };
+        });
+    }
+
+    let field_names = fields.iter().map(|f| f.ident.as_ref().unwrap());
+
+    let default_value = match container_default {
+        Some(default) => {
+            let default = default.to_expr()?;
+            quote! { let __default: Self = #default; }
+        }
+        None => quote! {},
+    };
+
+    let missing = match required.is_empty() {
+        true => quote! {},
+        false => quote! {
+            let mut __errors = ::multer_derive::Errors::new();
+            #(#required)*
+
+            if !__errors.is_empty() {
+                return Err(__errors.into());
+            }
+        },
+    };
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics ::multer_derive::FromMultipartAsync for #name #ty_generics #where_clause {
+            async fn from_multipart_async(
+                mut multipart: ::multer_derive::multer::Multipart<'_>,
+            ) -> Result<Self, ::multer_derive::Error> {
+                #default_value
+                #(#slots)*
+
+                while let Some(__field) = multipart.next_field().await? {
+                    let Some(__name) = __field.name() else {
+                        continue;
+                    };
+
+                    match __name {
+                        #(#arms)*
+                        _ => {}
+                    }
+                }
+
+                #missing
+                #(#values)*
+
+                Ok(Self {
+                    #(#field_names),*
+                })
+            }
+        }
+    })
+}
+
+fn named_struct_error(name: &syn::Ident) -> syn::Error {
+    syn::Error::new_spanned(
+        name,
+        "`FromMultipartAsync` can only be derived for structs with named fields",
+    )
+}
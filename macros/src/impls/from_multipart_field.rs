@@ -0,0 +1,86 @@
+use super::MulterAttribute;
+use proc_macro2::TokenStream;
+use quote::quote;
+use std::collections::HashMap;
+use syn::{Data, DeriveInput, Fields};
+
+pub fn derive_from_multipart_field(input: DeriveInput) -> syn::Result<TokenStream> {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let variants = match &input.data {
+        Data::Enum(data) => &data.variants,
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "`FromMultipartField` can only be derived for enums with unit variants",
+            ))
+        }
+    };
+
+    let container_attr = MulterAttribute::from_attributes(&input.attrs)?.unwrap_or_default();
+    container_attr.allow_only(
+        &["rename_all"],
+        "by `FromMultipartField`, only `rename_all` can be used on the enum",
+    )?;
+    let mut arms = Vec::new();
+    let mut allowed_values = Vec::new();
+    let mut values = HashMap::new();
+
+    for variant in variants {
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(syn::Error::new_spanned(
+                variant,
+                "`FromMultipartField` can only be derived for enums with unit variants",
+            ));
+        }
+
+        let attr = MulterAttribute::from_attributes(&variant.attrs)?.unwrap_or_default();
+        attr.allow_only(
+            &["rename"],
+            "by `FromMultipartField`, only `rename` can be used on the variants",
+        )?;
+
+        let variant_ident = &variant.ident;
+        let value = attr
+            .rename
+            .unwrap_or_else(|| match container_attr.rename_all {
+                Some(rule) => rule.apply_to_variant(&variant_ident.to_string()),
+                None => variant_ident.to_string(),
+            });
+
+        if let Some(other) = values.insert(value.clone(), variant_ident) {
+            return Err(syn::Error::new_spanned(
+                variant_ident,
+                format!("variant value `{value}` is already used by `{other}`"),
+            ));
+        }
+
+        arms.push(quote! {
+            #value => Ok(Self::#variant_ident),
+        });
+
+        allowed_values.push(format!("`{value}`"));
+    }
+
+    let expected = allowed_values.join(", ");
+
+    let expanded = quote! {
+        #[automatically_derived]
+        impl #impl_generics ::multer_derive::FromMultipartField for #name #ty_generics #where_clause {
+            fn from_field(field: &::multer_derive::MultipartField) -> Result<Self, ::multer_derive::Error> {
+                let text = field.try_text().map_err(::multer_derive::Error::new)?;
+
+                match text.as_str() {
+                    #(#arms)*
+                    other => Err(::multer_derive::Error::new(format!(
+                        "unknown variant `{}`, expected one of {}",
+                        other, #expected
+                    ))),
+                }
+            }
+        }
+    };
+
+    Ok(expanded)
+}
@@ -1,13 +1,27 @@
 use proc_macro2::TokenStream;
-use quote::quote;
+use quote::{quote, ToTokens};
 use std::collections::HashMap;
 use syn::{
+    ext::IdentExt,
     parse::{Parse, ParseStream},
     punctuated::Punctuated,
     token::Comma,
-    Data, DeriveInput, Field, Fields, GenericParam, Ident,
+    Attribute, Data, DataStruct, DeriveInput, Field, Fields, GenericArgument, GenericParam, Ident,
+    PathArguments, Type, Variant, WherePredicate,
 };
 
+mod case;
+use case::RenameRule;
+
+mod from_multipart_async;
+pub use from_multipart_async::derive_from_multipart_async;
+
+mod from_multipart_field;
+pub use from_multipart_field::derive_from_multipart_field;
+
+mod validate;
+use validate::Validation;
+
 pub fn derive_from_multipart(mut input: DeriveInput) -> syn::Result<TokenStream> {
     // We append generic bound to each generic
     // impl<A: FromMultipart, B: FromMultipart> for #name
@@ -20,17 +34,174 @@ pub fn derive_from_multipart(mut input: DeriveInput) -> syn::Result<TokenStream>
     }
 
     let name = input.ident;
-    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let container_attr = MulterAttribute::from_attributes(&input.attrs)?.unwrap_or_default();
+
+    if container_attr.tag.is_some() && !matches!(input.data, Data::Enum(_)) {
+        return Err(syn::Error::new_spanned(
+            &name,
+            "`tag` is only supported on enums",
+        ));
+    }
+
+    if container_attr.default.is_some() && !matches!(input.data, Data::Struct(_)) {
+        return Err(syn::Error::new_spanned(
+            &name,
+            "`default` is only supported on structs",
+        ));
+    }
+
+    let container_default = container_attr.default.as_ref();
+
+    let constraints = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => derive_constraints(&fields.named, &container_attr)?,
+            _ => Vec::new(),
+        },
+        Data::Enum(data) => {
+            let mut constraints = Vec::new();
+            for variant in &data.variants {
+                if let Fields::Named(fields) = &variant.fields {
+                    let variant_attr = MulterAttribute::default();
+                    constraints.extend(derive_constraints(&fields.named, &variant_attr)?);
+                }
+            }
+
+            constraints
+        }
+        Data::Union(_) => Vec::new(),
+    };
+
+    let constraints_fn = match constraints.as_slice() {
+        [] => quote! {},
+        _ => quote! {
+            fn constraints() -> ::multer_derive::FormConstraints {
+                ::multer_derive::FormConstraints::new() #(#constraints)*
+            }
+        },
+    };
+
+    // The `validator` or `garde` errors use the names of the fields, which are renamed to the form names
+    let mut renames = Vec::new();
+    if let Data::Struct(DataStruct {
+        fields: Fields::Named(fields),
+        ..
+    }) = &input.data
+    {
+        for f in &fields.named {
+            let field_name = f.ident.as_ref().unwrap().to_string();
+            let attr = MulterAttribute::from_attributes(&f.attrs)?;
+            let field_name_str = form_name(&field_name, attr.as_ref(), &container_attr);
+
+            if field_name != field_name_str {
+                renames.push(quote! { (#field_name, #field_name_str) });
+            }
+        }
+    }
 
-    let fields = match input.data {
+    let body = match input.data {
         Data::Struct(data) => match data.fields {
-            Fields::Named(fields) => fields.named,
-            _ => panic!("Only named fields are supported"),
+            Fields::Named(fields) => {
+                let constructor = quote! { Self };
+                derive_named_fields(constructor, &fields.named, &container_attr)?
+            }
+            Fields::Unnamed(fields) => {
+                if container_attr.collect_errors {
+                    return Err(syn::Error::new_spanned(
+                        &name,
+                        "`collect_errors` is only supported on structs with named fields and enums",
+                    ));
+                }
+
+                let (body, bounds) = derive_unnamed_fields(&fields.unnamed, container_default)?;
+                let where_clause = input.generics.make_where_clause();
+                where_clause.predicates.extend(bounds);
+                body
+            }
+            Fields::Unit => {
+                return Err(syn::Error::new_spanned(
+                    &name,
+                    "`FromMultipart` cannot be derived for unit structs",
+                ))
+            }
         },
-        _ => panic!("Only structs are supported"),
+        Data::Enum(data) => derive_tagged_enum(&name, &data.variants, &container_attr)?,
+        Data::Union(data) => {
+            return Err(syn::Error::new_spanned(
+                data.union_token,
+                "`FromMultipart` cannot be derived for unions",
+            ))
+        }
+    };
+
+    let validate_fn = match container_attr.validate_fn.as_deref() {
+        Some(validate_fn) => {
+            let validate_fn = syn::parse_str::<syn::Path>(validate_fn)?;
+            quote! {
+                #validate_fn(&__value).map_err(|err| __ctx.scope(::multer_derive::Error::from(err)))?;
+            }
+        }
+        None => quote! {},
+    };
+
+    // The container validations run after the value is constructed,
+    // the `validator` or `garde` validations only if the type implements them and the feature is enabled
+    let body = quote! {
+        let __ctx = _ctx.clone();
+        let __value: Result<Self, ::multer_derive::Error> = { #body };
+        let __value = __value?;
+
+        {
+            #[allow(unused_imports)]
+            use ::multer_derive::validate::__private::{NoValidation as _, ViaGarde as _, ViaValidator as _};
+            (&&&::multer_derive::validate::__private::External(&__value))
+                .__multer_validate(&__ctx, &[#(#renames),*])?;
+        }
+
+        #validate_fn
+        Ok(__value)
+    };
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    // The container notation is used for its own fields and all the nested ones
+    let notation = match container_attr.notation.as_deref() {
+        Some("brackets") => quote! {
+            let _ctx = ::multer_derive::FormContext { notation: ::multer_derive::Notation::Brackets, .._ctx };
+        },
+        Some("dots") => quote! {
+            let _ctx = ::multer_derive::FormContext { notation: ::multer_derive::Notation::Dots, .._ctx };
+        },
+        _ => quote! {},
+    };
+
+    let expanded = quote! {
+        #[automatically_derived]
+        impl #impl_generics ::multer_derive::FromMultipart for #name #ty_generics #where_clause {
+            fn from_multipart<'a>(multipart: &::multer_derive::MultipartForm, _ctx: ::multer_derive::FormContext<'_>) -> Result<Self, ::multer_derive::Error> {
+                #notation
+                #body
+            }
+
+            #constraints_fn
+        }
     };
 
-    let field_attrs = get_fields_attributes(&fields)?;
+    // return the tokens
+    Ok(expanded)
+}
+
+/// Generates the code to parse each named field and construct the value using `constructor`,
+/// which could be `Self` or an enum variant `Self::Variant`.
+///
+/// When a field is missing from the form its value is taken from the field `default`,
+/// or from the container `default` value if any.
+fn derive_named_fields(
+    constructor: TokenStream,
+    fields: &Punctuated<Field, Comma>,
+    container_attr: &MulterAttribute,
+) -> syn::Result<TokenStream> {
+    let field_attrs = get_fields_attributes(fields)?;
+    let container_default = container_attr.default.as_ref();
 
     let field_names = fields
         .iter()
@@ -39,18 +210,93 @@ pub fn derive_from_multipart(mut input: DeriveInput) -> syn::Result<TokenStream>
         .collect::<Vec<_>>();
 
     let mut field_parsers = Vec::new();
+    let mut parsed_fields = Vec::new();
+    let mut form_names = HashMap::new();
 
     for f in fields {
         let original_name = f.ident.as_ref().unwrap();
         let name_str = original_name.to_string();
         let attr = field_attrs.get(&name_str).cloned();
-        let field_name_str = attr
-            .clone()
-            .and_then(|attr| attr.rename)
-            .unwrap_or_else(|| original_name.to_string().clone());
+        let field_name_str = form_name(&name_str, attr.as_ref(), container_attr);
+
+        if attr.as_ref().is_some_and(|attr| attr.tag.is_some()) {
+            return Err(syn::Error::new_spanned(
+                original_name,
+                "`tag` is only supported on enums",
+            ));
+        }
+
+        if attr.as_ref().is_some_and(|attr| attr.validate_fn.is_some()) {
+            return Err(syn::Error::new_spanned(
+                original_name,
+                "`validate = \"...\"` is only supported on structs and enums, use `validate(...)` on fields",
+            ));
+        }
+
+        if attr.as_ref().is_some_and(|attr| attr.collect_errors) {
+            return Err(syn::Error::new_spanned(
+                original_name,
+                "`collect_errors` is only supported on structs and enums",
+            ));
+        }
+
+        if attr
+            .as_ref()
+            .is_some_and(|attr| attr.rename.is_some() && attr.prefix.is_some())
+        {
+            return Err(syn::Error::new_spanned(
+                original_name,
+                "`rename` and `prefix` cannot be used together, `prefix` already sets the name",
+            ));
+        }
+
+        let kind = match &attr {
+            Some(attr) => attr.field_kind(original_name)?,
+            None => FieldKind::Value,
+        };
+
+        // Skipped fields are never read from the form
+        if kind == FieldKind::Skip {
+            let value = match (
+                attr.as_ref().and_then(|attr| attr.default.as_ref()),
+                container_default,
+            ) {
+                (Some(default), _) => default.to_expr()?,
+                (None, Some(_)) => quote! { __default.#original_name },
+                (None, None) => DefaultValue::Default.to_expr()?,
+            };
+
+            field_parsers.push(quote! {
+                let #original_name = #value;
+            });
+
+            continue;
+        }
 
-        let field_ty = f.ty;
-        let parser = match attr.and_then(|s| s.with) {
+        // Flattened fields are parsed using the parent names
+        if kind != FieldKind::Flatten {
+            if let Some(other) = form_names.insert(field_name_str.clone(), original_name) {
+                return Err(syn::Error::new_spanned(
+                    original_name,
+                    format!("form field `{field_name_str}` is already used by field `{other}`"),
+                ));
+            }
+        }
+
+        let field_ty = &f.ty;
+        let default = attr.as_ref().and_then(|attr| attr.default.clone());
+        let field_ctx = field_context(kind);
+
+        if kind == FieldKind::Indexed && attr.as_ref().is_some_and(|attr| attr.with.is_some()) {
+            return Err(syn::Error::new_spanned(
+                original_name,
+                "`indexed` cannot be used with `with`",
+            ));
+        }
+
+        // With `collect_errors` each field is parsed into a `Result` instead of using `?`
+        let collect = container_attr.collect_errors;
+        let call = match attr.and_then(|s| s.with) {
             Some(with) => {
                 let from_multipart_fn = match syn::parse_str::<syn::Path>(&with) {
                     Ok(p) => p,
@@ -59,82 +305,925 @@ pub fn derive_from_multipart(mut input: DeriveInput) -> syn::Result<TokenStream>
                     }
                 };
 
-                quote! { #from_multipart_fn ( multipart, ::multer_derive::FormContext { field_name: Some( #field_name_str ) } )? }
+                // The errors of the function are attached to the field, as any other field
+                quote! {
+                    #from_multipart_fn ( multipart, #field_ctx ).map_err(|err| {
+                        let index = multipart.get_by_name(&__field_path).map(|field| field.index());
+                        ::multer_derive::Error::from(err).for_field(#field_name_str, &__field_path, index)
+                    })
+                }
+            }
+            None if kind == FieldKind::Indexed => {
+                quote! {
+                    <::multer_derive::Indexed<_> as ::multer_derive::FromMultipart>::from_multipart(
+                        multipart,
+                        #field_ctx,
+                    )
+                    .map(::multer_derive::Indexed::into_inner)
+                }
             }
             None => {
                 quote! {
                     <#field_ty as ::multer_derive::FromMultipart>::from_multipart(
                         multipart,
-                        ::multer_derive::FormContext {
-                            field_name: Some( #field_name_str ),
-                        },
-                    )?
+                        #field_ctx,
+                    )
                 }
             }
         };
 
+        // Flattened fields don't have a name to check if are missing
+        let fallback = match (default, container_default) {
+            _ if kind == FieldKind::Flatten => None,
+            (Some(default), _) => Some(default.to_expr()?),
+            (None, Some(_)) => Some(quote! { __default.#original_name }),
+            (None, None) => None,
+        };
+
+        let parser = match fallback {
+            Some(fallback) => {
+                let exists = field_exists(kind);
+                match collect {
+                    true => quote! {
+                        match #exists {
+                            true => #call,
+                            false => Ok(#fallback),
+                        }
+                    },
+                    false => quote! {
+                        match #exists {
+                            true => #call?,
+                            false => #fallback,
+                        }
+                    },
+                }
+            }
+            None if collect => call,
+            None => quote! { #call? },
+        };
+
+        let parser = match collect {
+            true => quote! {
+                match #parser {
+                    Ok(value) => Some(value),
+                    Err(err) => {
+                        __errors.push(err);
+                        None
+                    }
+                }
+            },
+            false => parser,
+        };
+
+        let validations = field_attrs
+            .get(&name_str)
+            .map(|attr| attr.validate.as_slice())
+            .unwrap_or_default();
+
+        let parser = match validations {
+            [] => parser,
+            _ => derive_validations(parser, validations, field_ty, &field_name_str, collect)?,
+        };
+
         field_parsers.push(quote! {
-            let #original_name = #parser;
+            let #original_name = {
+                let __field_path = _ctx.field_path(#field_name_str);
+                #parser
+            };
         });
+
+        parsed_fields.push(original_name);
     }
 
-    let expanded = quote! {
-        #[automatically_derived]
-        impl #impl_generics ::multer_derive::FromMultipart for #name #ty_generics #where_clause {
-            fn from_multipart<'a>(multipart: &::multer_derive::MultipartForm, _ctx: ::multer_derive::FormContext<'_>) -> Result<Self, ::multer_derive::Error> {
-                #(#field_parsers)*
+    let default_value = match container_default {
+        Some(default) => {
+            let default = default.to_expr()?;
+            quote! { let __default: Self = #default; }
+        }
+        None => quote! {},
+    };
+
+    // Any missing value means at least one error was collected
+    let collect_errors = match container_attr.collect_errors {
+        true if !parsed_fields.is_empty() => quote! {
+            let (#(Some(#parsed_fields),)*) = (#(#parsed_fields,)*) else {
+                return Err(__errors.into());
+            };
+        },
+        _ => quote! {},
+    };
+
+    let errors = match container_attr.collect_errors {
+        true => quote! { let mut __errors = ::multer_derive::Errors::new(); },
+        false => quote! {},
+    };
+
+    Ok(quote! {
+        #default_value
+        #errors
+        #(#field_parsers)*
+        #collect_errors
+
+        Ok(#constructor {
+            #(#field_names),*
+        })
+    })
+}
+
+/// Returns the name of a field in the form, using the `rename` or `prefix` of the field,
+/// or the `rename_all` rule of the container.
+fn form_name(
+    field_name: &str,
+    attr: Option<&MulterAttribute>,
+    container_attr: &MulterAttribute,
+) -> String {
+    attr.and_then(|attr| attr.rename.clone().or(attr.prefix.clone()))
+        .unwrap_or_else(|| match container_attr.rename_all {
+            Some(rule) => rule.apply_to_field(field_name),
+            None => field_name.to_owned(),
+        })
+}
+
+/// Generates the calls to `FormConstraints` with the `limit` of each field,
+/// including the limits of the nested and flattened fields.
+fn derive_constraints(
+    fields: &Punctuated<Field, Comma>,
+    container_attr: &MulterAttribute,
+) -> syn::Result<Vec<TokenStream>> {
+    let notation = match container_attr.notation.as_deref() {
+        Some("dots") => quote! { ::multer_derive::Notation::Dots },
+        _ => quote! { ::multer_derive::Notation::Brackets },
+    };
+
+    let mut constraints = Vec::new();
+
+    for f in fields {
+        let Some(attr) = MulterAttribute::from_attributes(&f.attrs)? else {
+            continue;
+        };
+
+        let ident = f.ident.as_ref().unwrap();
+        let name = form_name(&ident.to_string(), Some(&attr), container_attr);
+        let field_ty = &f.ty;
 
-                Ok(Self {
-                    #(#field_names),*
-                })
+        // Types parsed using `with` may not implement `FromMultipart`
+        match attr.field_kind(ident)? {
+            FieldKind::Value => {
+                if let Some(limit) = attr.limit {
+                    constraints.push(quote! { .max_size_for(#name, #limit) });
+                }
             }
+            FieldKind::Nested if attr.with.is_none() => constraints.push(quote! {
+                .merge(<#field_ty as ::multer_derive::FromMultipart>::constraints().prefixed(#name, #notation))
+            }),
+            FieldKind::Indexed if attr.with.is_none() => {
+                if let Some(item_ty) = vec_item(field_ty) {
+                    constraints.push(quote! {
+                        .merge(<#item_ty as ::multer_derive::FromMultipart>::constraints().indexed(#notation).prefixed(#name, #notation))
+                    });
+                }
+            }
+            FieldKind::Flatten if attr.with.is_none() => constraints.push(quote! {
+                .merge(<#field_ty as ::multer_derive::FromMultipart>::constraints())
+            }),
+            _ => {}
+        }
+    }
+
+    Ok(constraints)
+}
+
+/// Parses a size like `512`, `4KiB`, `5MiB` or `1GB` to bytes.
+fn parse_size(value: &str) -> Result<u64, String> {
+    let value = value.trim();
+    let end = value
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(value.len());
+
+    let (number, unit) = value.split_at(end);
+    let multiplier: u64 = match unit.trim() {
+        "" | "B" => 1,
+        "KB" => 1000,
+        "MB" => 1000 * 1000,
+        "GB" => 1000 * 1000 * 1000,
+        "KiB" => 1024,
+        "MiB" => 1024 * 1024,
+        "GiB" => 1024 * 1024 * 1024,
+        unit => {
+            return Err(format!(
+                "unknown size unit `{unit}`, expected one of: B, KB, MB, GB, KiB, MiB, GiB"
+            ))
         }
     };
 
-    // return the tokens
-    Ok(expanded)
+    number
+        .parse::<u64>()
+        .ok()
+        .and_then(|number| number.checked_mul(multiplier))
+        .ok_or_else(|| format!("invalid size `{value}`, expected a size like `512KiB` or `5MiB`"))
 }
 
-#[derive(Debug, Clone)]
+/// Generates the code to check the `validations` of the value returned by `parser`.
+///
+/// `Option` fields are only validated if they have a value. When collecting errors
+/// the parser returns an `Option` and the value is discarded if any validation fails.
+fn derive_validations(
+    parser: TokenStream,
+    validations: &[Validation],
+    field_ty: &Type,
+    field_name: &str,
+    collect: bool,
+) -> syn::Result<TokenStream> {
+    let checks = validations
+        .iter()
+        .map(Validation::to_check)
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    let scope = quote! {
+        |err: ::multer_derive::FieldError| err.with_name(#field_name).with_path(&*__field_path)
+    };
+
+    let checks = match collect {
+        true => quote! {
+            #(
+                if let Err(err) = #checks {
+                    __errors.push((#scope)(err));
+                }
+            )*
+        },
+        false => quote! {
+            #( #checks.map_err(#scope)?; )*
+        },
+    };
+
+    let checks = match is_option(field_ty) {
+        true => quote! {
+            if let Some(__value) = &__parsed {
+                #checks
+            }
+        },
+        false => quote! {
+            let __value = &__parsed;
+            #checks
+        },
+    };
+
+    let validated = match collect {
+        true => quote! {
+            match #parser {
+                Some(__parsed) => {
+                    let __errors_len = __errors.len();
+                    #checks
+
+                    match __errors.len() == __errors_len {
+                        true => Some(__parsed),
+                        false => None,
+                    }
+                }
+                None => None,
+            }
+        },
+        false => quote! {
+            let __parsed = #parser;
+            #checks
+            __parsed
+        },
+    };
+
+    Ok(validated)
+}
+
+/// Returns `true` if the type is an `Option<T>`.
+fn is_option(ty: &Type) -> bool {
+    let Type::Path(type_path) = ty else {
+        return false;
+    };
+
+    type_path
+        .path
+        .segments
+        .last()
+        .is_some_and(|segment| segment.ident == "Option")
+}
+
+/// Returns the type of the items of a `Vec<T>`.
+fn vec_item(ty: &Type) -> Option<&Type> {
+    let Type::Path(type_path) = ty else {
+        return None;
+    };
+
+    let segment = type_path.path.segments.last()?;
+    if segment.ident != "Vec" {
+        return None;
+    }
+
+    match &segment.arguments {
+        PathArguments::AngleBracketed(args) => match args.args.first() {
+            Some(GenericArgument::Type(ty)) if args.args.len() == 1 => Some(ty),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// How a field is parsed from the form.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FieldKind {
+    /// A field parsed from the form field with its name.
+    Value,
+
+    // #[multer(nested)] or #[multer(prefix = "...")]
+    Nested,
+
+    // #[multer(indexed)]
+    Indexed,
+
+    // #[multer(flatten)]
+    Flatten,
+
+    // #[multer(skip)]
+    Skip,
+}
+
+/// Generates the `FormContext` for a field which full name is in `__field_path`.
+///
+/// Value fields keep the `prefix` of the parent so errors can report the local name,
+/// nested fields receive the name as the `prefix` of its own fields,
+/// and flattened fields receive the same `prefix` of the parent.
+fn field_context(kind: FieldKind) -> TokenStream {
+    match kind {
+        FieldKind::Value => quote! {
+            ::multer_derive::FormContext {
+                field_name: Some(&__field_path),
+                prefix: _ctx.prefix,
+                notation: _ctx.notation,
+            }
+        },
+        FieldKind::Nested => quote! {
+            ::multer_derive::FormContext {
+                field_name: None,
+                prefix: Some(&__field_path),
+                notation: _ctx.notation,
+            }
+        },
+        FieldKind::Indexed => quote! {
+            ::multer_derive::FormContext {
+                field_name: Some(&__field_path),
+                prefix: Some(&__field_path),
+                notation: _ctx.notation,
+            }
+        },
+        FieldKind::Flatten => quote! {
+            ::multer_derive::FormContext {
+                field_name: None,
+                prefix: _ctx.prefix,
+                notation: _ctx.notation,
+            }
+        },
+        FieldKind::Skip => unreachable!("skipped fields are not parsed"),
+    }
+}
+
+/// Generates the expression that checks if the field in `__field_path` is in the form.
+fn field_exists(kind: FieldKind) -> TokenStream {
+    match kind {
+        FieldKind::Nested | FieldKind::Indexed => quote! {
+            multipart.contains_nested(&__field_path, _ctx.notation)
+        },
+        FieldKind::Value | FieldKind::Flatten | FieldKind::Skip => {
+            quote! { multipart.get_by_name(&__field_path).is_some() }
+        }
+    }
+}
+
+/// Generates the code to parse a tuple struct.
+///
+/// A newtype `struct Email(String)` delegates to the inner type using the caller's context,
+/// otherwise each field is parsed from the form field at its position or from the name given
+/// with `#[multer(rename = "...")]`. In a nested form the positions are joined with the prefix,
+/// like `point[0]`. Returns the `where` predicates the parsed fields require.
+fn derive_unnamed_fields(
+    fields: &Punctuated<Field, Comma>,
+    container_default: Option<&DefaultValue>,
+) -> syn::Result<(TokenStream, Vec<WherePredicate>)> {
+    let mut field_attrs = Vec::new();
+    for f in fields {
+        let attr = MulterAttribute::from_attributes(&f.attrs)?.unwrap_or_default();
+
+        if !attr.validate.is_empty() || attr.validate_fn.is_some() {
+            return Err(syn::Error::new_spanned(
+                f,
+                "`validate` is only supported on named fields",
+            ));
+        }
+
+        if attr.limit.is_some() {
+            return Err(syn::Error::new_spanned(
+                f,
+                "`limit` is only supported on named fields",
+            ));
+        }
+
+        if attr.nested || attr.prefix.is_some() || attr.indexed || attr.flatten {
+            return Err(syn::Error::new_spanned(
+                f,
+                "`nested`, `prefix`, `indexed` and `flatten` are only supported on named fields",
+            ));
+        }
+
+        field_attrs.push(attr);
+    }
+
+    // Newtype, we forward the context to the inner type
+    if fields.len() == 1 && field_attrs[0].rename.is_none() && !field_attrs[0].skip {
+        let field_ty = &fields[0].ty;
+        let parser = match field_attrs[0].with.as_deref() {
+            Some(with) => {
+                let from_multipart_fn = syn::parse_str::<syn::Path>(with)?;
+                quote! { #from_multipart_fn ( multipart, _ctx )? }
+            }
+            None => {
+                quote! { <#field_ty as ::multer_derive::FromMultipart>::from_multipart(multipart, _ctx)? }
+            }
+        };
+
+        // The field is missing when the caller gives us a name that is not in the form
+        let body = match (&field_attrs[0].default, container_default) {
+            (Some(default), _) => {
+                let default = default.to_expr()?;
+                quote! {
+                    match _ctx.field_name {
+                        Some(name) if multipart.get_by_name(name).is_none() => Ok(Self(#default)),
+                        _ => Ok(Self(#parser)),
+                    }
+                }
+            }
+            (None, Some(default)) => {
+                let default = default.to_expr()?;
+                quote! {
+                    match _ctx.field_name {
+                        Some(name) if multipart.get_by_name(name).is_none() => Ok(#default),
+                        _ => Ok(Self(#parser)),
+                    }
+                }
+            }
+            (None, None) => quote! { Ok(Self(#parser)) },
+        };
+
+        return Ok((body, Vec::new()));
+    }
+
+    let mut bounds = Vec::new();
+    let mut field_parsers = Vec::new();
+    let mut form_names = HashMap::new();
+
+    // The position in the form of the next positional field, skipped fields are not counted
+    let mut index = 0_usize;
+
+    for (position, (f, attr)) in fields.iter().zip(field_attrs).enumerate() {
+        let field_ty = &f.ty;
+        let member = syn::Index::from(position);
+        let fallback = match (&attr.default, container_default) {
+            (Some(default), _) => Some(default.to_expr()?),
+            (None, Some(_)) => Some(quote! { __default.#member }),
+            (None, None) => None,
+        };
+
+        if attr.field_kind(f)? == FieldKind::Skip {
+            let value = match fallback {
+                Some(fallback) => fallback,
+                None => DefaultValue::Default.to_expr()?,
+            };
+
+            field_parsers.push(value);
+            continue;
+        }
+
+        if let Some(rename) = attr.rename.as_deref() {
+            if let Some(other) = form_names.insert(rename.to_owned(), position) {
+                return Err(syn::Error::new_spanned(
+                    f,
+                    format!("form field `{rename}` is already used by field `{other}`"),
+                ));
+            }
+        }
+
+        let field_ctx = field_context(FieldKind::Value);
+        let parser = match (&attr.rename, &attr.with) {
+            (Some(_), Some(with)) => {
+                let from_multipart_fn = syn::parse_str::<syn::Path>(with)?;
+                quote! { #from_multipart_fn ( multipart, #field_ctx )? }
+            }
+            (Some(_), None) => {
+                quote! {
+                    <#field_ty as ::multer_derive::FromMultipart>::from_multipart(
+                        multipart,
+                        #field_ctx,
+                    )?
+                }
+            }
+            (None, Some(_)) => {
+                return Err(syn::Error::new_spanned(
+                    f,
+                    "`with` requires a `rename` on positional fields",
+                ));
+            }
+            (None, None) => {
+                bounds.push(syn::parse_quote! { #field_ty: ::multer_derive::FromMultipartField });
+
+                let index_str = index.to_string();
+                let missing = match &fallback {
+                    Some(fallback) => quote! { #fallback },
+                    None => quote! {{
+                        let error = ::multer_derive::FieldError::new(
+                            ::multer_derive::FieldErrorKind::Missing,
+                        )
+                        .with_name(#index_str)
+                        .with_path(&*__field_path);
+
+                        return Err(match _ctx.prefix {
+                            Some(_) => error,
+                            None => error.with_index(#index),
+                        }
+                        .into());
+                    }},
+                };
+
+                // Inside a nested form the fields are named by its position, like `point[0]`
+                let parser = quote! {{
+                    let __field_path = _ctx.field_path(#index_str);
+                    let __field = match _ctx.prefix {
+                        Some(_) => multipart.get_by_name(&__field_path),
+                        None => multipart.get(#index),
+                    };
+
+                    match __field {
+                        Some(field) => <#field_ty as ::multer_derive::FromMultipartField>::from_field(field)
+                            .map_err(|err| err.for_field(#index_str, &__field_path, Some(field.index())))?,
+                        None => #missing,
+                    }
+                }};
+
+                index += 1;
+                parser
+            }
+        };
+
+        let parser = match (&attr.rename, fallback) {
+            (Some(rename), fallback) => {
+                let exists = field_exists(FieldKind::Value);
+                let parser = match fallback {
+                    Some(fallback) => quote! {
+                        match #exists {
+                            true => #parser,
+                            false => #fallback,
+                        }
+                    },
+                    None => parser,
+                };
+
+                quote! {
+                    {
+                        let __field_path = _ctx.field_path(#rename);
+                        #parser
+                    }
+                }
+            }
+            (None, _) => parser,
+        };
+
+        field_parsers.push(parser);
+    }
+
+    let default_value = match container_default {
+        Some(default) => {
+            let default = default.to_expr()?;
+            quote! { let __default: Self = #default; }
+        }
+        None => quote! {},
+    };
+
+    let body = quote! {
+        #default_value
+        Ok(Self( #(#field_parsers),* ))
+    };
+
+    Ok((body, bounds))
+}
+
+/// Generates the code to parse an enum, the variant is selected by the value
+/// of the form field declared with `#[multer(tag = "...")]`.
+fn derive_tagged_enum(
+    name: &Ident,
+    variants: &Punctuated<Variant, Comma>,
+    container_attr: &MulterAttribute,
+) -> syn::Result<TokenStream> {
+    let Some(tag) = container_attr.tag.as_deref() else {
+        return Err(syn::Error::new_spanned(
+            name,
+            "enums require a `#[multer(tag = \"...\")]` attribute to select the variant, \
+            or derive `FromMultipartField` for enums with unit variants",
+        ));
+    };
+
+    let mut arms = Vec::new();
+    let mut allowed_values = Vec::new();
+    let mut tag_values = HashMap::new();
+
+    for variant in variants {
+        let attr = MulterAttribute::from_attributes(&variant.attrs)?.unwrap_or_default();
+        let variant_ident = &variant.ident;
+        let value = attr
+            .rename
+            .unwrap_or_else(|| match container_attr.rename_all {
+                Some(rule) => rule.apply_to_variant(&variant_ident.to_string()),
+                None => variant_ident.to_string(),
+            });
+
+        if let Some(other) = tag_values.insert(value.clone(), variant_ident) {
+            return Err(syn::Error::new_spanned(
+                variant_ident,
+                format!("variant value `{value}` is already used by `{other}`"),
+            ));
+        }
+
+        let parser = match &variant.fields {
+            Fields::Named(fields) => {
+                let constructor = quote! { Self::#variant_ident };
+                let variant_attr = MulterAttribute {
+                    rename_all: container_attr.rename_all,
+                    collect_errors: container_attr.collect_errors,
+                    ..Default::default()
+                };
+
+                derive_named_fields(constructor, &fields.named, &variant_attr)?
+            }
+            Fields::Unit => quote! { Ok(Self::#variant_ident) },
+            Fields::Unnamed(_) => {
+                return Err(syn::Error::new_spanned(
+                    variant,
+                    "tagged enums only support variants with named fields or unit variants",
+                ))
+            }
+        };
+
+        arms.push(quote! {
+            #value => { #parser }
+        });
+
+        allowed_values.push(format!("`{value}`"));
+    }
+
+    let expected = allowed_values.join(", ");
+
+    Ok(quote! {
+        let tag_path = _ctx.field_path(#tag);
+        let tag_error = |kind| {
+            ::multer_derive::FieldError::new(kind)
+                .with_name(#tag)
+                .with_path(&*tag_path)
+        };
+
+        let tag_field = multipart
+            .get_by_name(&tag_path)
+            .ok_or_else(|| tag_error(::multer_derive::FieldErrorKind::Missing))?;
+
+        let tag_value = tag_field.try_text().map_err(|err| {
+            tag_error(::multer_derive::FieldErrorKind::Parse)
+                .with_index(tag_field.index())
+                .with_source(err)
+        })?;
+
+        match tag_value.as_str() {
+            #(#arms)*
+            other => Err(tag_error(::multer_derive::FieldErrorKind::Parse)
+                .with_index(tag_field.index())
+                .with_source(format!("unknown variant `{}`, expected one of {}", other, #expected))
+                .into()),
+        }
+    })
+}
+
+#[derive(Debug, Clone, Default)]
 struct MulterAttribute {
     // #[multer(rename = "other_name")]
     rename: Option<String>,
 
     // #[multer(with = "path::to::function")]
     with: Option<String>,
+
+    // #[multer(tag = "field_name")]
+    tag: Option<String>,
+
+    // #[multer(default)] or #[multer(default = "path::to::function")]
+    default: Option<DefaultValue>,
+
+    // #[multer(rename_all = "camelCase")]
+    rename_all: Option<RenameRule>,
+
+    // #[multer(nested)]
+    nested: bool,
+
+    // #[multer(indexed)]
+    indexed: bool,
+
+    // #[multer(flatten)]
+    flatten: bool,
+
+    // #[multer(skip)]
+    skip: bool,
+
+    // #[multer(prefix = "field_name")]
+    prefix: Option<String>,
+
+    // #[multer(notation = "brackets")] or #[multer(notation = "dots")]
+    notation: Option<String>,
+
+    // #[multer(collect_errors)]
+    collect_errors: bool,
+
+    // #[multer(validate(length(min = 1), email))]
+    validate: Vec<Validation>,
+
+    // #[multer(validate = "path::to::function")]
+    validate_fn: Option<String>,
+
+    // #[multer(limit = "5MiB")]
+    limit: Option<u64>,
+
+    // The keys in the order they were declared
+    keys: Vec<Ident>,
 }
 
+#[derive(Debug, Clone)]
+enum DefaultValue {
+    // #[multer(default)]
+    Default,
+
+    // #[multer(default = "path::to::function")]
+    Path(String),
+}
+
+impl DefaultValue {
+    /// Returns the expression that produces the default value.
+    fn to_expr(&self) -> syn::Result<TokenStream> {
+        match self {
+            DefaultValue::Default => Ok(quote! { ::std::default::Default::default() }),
+            DefaultValue::Path(path) => {
+                let default_fn = syn::parse_str::<syn::Path>(path)?;
+                Ok(quote! { #default_fn() })
+            }
+        }
+    }
+}
+
+/// The keys supported by `#[multer(...)]`.
+const MULTER_ATTRIBUTE_KEYS: &[&str] = &[
+    "rename",
+    "with",
+    "tag",
+    "default",
+    "rename_all",
+    "nested",
+    "indexed",
+    "flatten",
+    "skip",
+    "prefix",
+    "notation",
+    "collect_errors",
+    "validate",
+    "limit",
+];
+
 impl Parse for MulterAttribute {
     fn parse(input: ParseStream) -> syn::Result<Self> {
-        let mut multer_attribute = MulterAttribute {
-            rename: None,
-            with: None,
-        };
+        let mut multer_attribute = MulterAttribute::default();
 
         while !input.is_empty() {
-            let lookahead = input.lookahead1();
-
-            if input.peek(Ident) {
-                let path: syn::Path = input.parse()?;
+            let key: Ident = input.call(Ident::parse_any)?;
 
+            match key.to_string().as_str() {
                 // #[multer(rename = "...")]
-                if path.is_ident("rename") {
+                "rename" => {
                     let _: syn::Token![=] = input.parse()?;
                     let rename_value: syn::LitStr = input.parse()?;
-                    multer_attribute.rename = Some(rename_value.value());
+                    set_once(&mut multer_attribute.rename, rename_value.value(), &key)?;
                 }
                 // #[multer(with = "...")]
-                else if path.is_ident("with") {
+                "with" => {
                     let _: syn::Token![=] = input.parse()?;
                     let with_value: syn::LitStr = input.parse()?;
-                    multer_attribute.with = Some(with_value.value());
-                } else {
-                    return Err(lookahead.error());
+                    set_once(&mut multer_attribute.with, with_value.value(), &key)?;
+                }
+                // #[multer(tag = "...")]
+                "tag" => {
+                    let _: syn::Token![=] = input.parse()?;
+                    let tag_value: syn::LitStr = input.parse()?;
+                    set_once(&mut multer_attribute.tag, tag_value.value(), &key)?;
+                }
+                // #[multer(rename_all = "...")]
+                "rename_all" => {
+                    let _: syn::Token![=] = input.parse()?;
+                    let rule_value: syn::LitStr = input.parse()?;
+                    let rule = RenameRule::from_str(&rule_value.value())
+                        .map_err(|message| syn::Error::new_spanned(&rule_value, message))?;
+
+                    set_once(&mut multer_attribute.rename_all, rule, &key)?;
+                }
+                // #[multer(nested)]
+                "nested" => set_flag(&mut multer_attribute.nested, &key)?,
+                // #[multer(indexed)]
+                "indexed" => set_flag(&mut multer_attribute.indexed, &key)?,
+                // #[multer(flatten)]
+                "flatten" => set_flag(&mut multer_attribute.flatten, &key)?,
+                // #[multer(skip)]
+                "skip" => set_flag(&mut multer_attribute.skip, &key)?,
+                // #[multer(collect_errors)]
+                "collect_errors" => set_flag(&mut multer_attribute.collect_errors, &key)?,
+                // #[multer(limit = "...")]
+                "limit" => {
+                    let _: syn::Token![=] = input.parse()?;
+                    let limit_value: syn::LitStr = input.parse()?;
+                    let limit = parse_size(&limit_value.value())
+                        .map_err(|message| syn::Error::new_spanned(&limit_value, message))?;
+
+                    set_once(&mut multer_attribute.limit, limit, &key)?;
+                }
+                // #[multer(validate = "...")]
+                "validate" if input.peek(syn::Token![=]) => {
+                    let _: syn::Token![=] = input.parse()?;
+                    let validate_value: syn::LitStr = input.parse()?;
+                    set_once(
+                        &mut multer_attribute.validate_fn,
+                        validate_value.value(),
+                        &key,
+                    )?;
+                }
+                // #[multer(validate(...))]
+                "validate" => {
+                    if !multer_attribute.validate.is_empty() {
+                        return Err(syn::Error::new_spanned(
+                            &key,
+                            format!("duplicate `multer` attribute `{key}`"),
+                        ));
+                    }
+
+                    multer_attribute.validate = Validation::parse_list(input)?;
+                }
+                // #[multer(prefix = "...")]
+                "prefix" => {
+                    let _: syn::Token![=] = input.parse()?;
+                    let prefix_value: syn::LitStr = input.parse()?;
+                    set_once(&mut multer_attribute.prefix, prefix_value.value(), &key)?;
+                }
+                // #[multer(notation = "...")]
+                "notation" => {
+                    let _: syn::Token![=] = input.parse()?;
+                    let notation_value: syn::LitStr = input.parse()?;
+                    let notation = notation_value.value();
+
+                    if notation != "brackets" && notation != "dots" {
+                        return Err(syn::Error::new_spanned(
+                            &notation_value,
+                            format!("unknown notation `{notation}`, expected `brackets` or `dots`"),
+                        ));
+                    }
+
+                    set_once(&mut multer_attribute.notation, notation, &key)?;
+                }
+                // #[multer(default)] or #[multer(default = "...")]
+                "default" => {
+                    let default_value = if input.peek(syn::Token![=]) {
+                        let _: syn::Token![=] = input.parse()?;
+                        let path_value: syn::LitStr = input.parse()?;
+                        DefaultValue::Path(path_value.value())
+                    } else {
+                        DefaultValue::Default
+                    };
+
+                    set_once(&mut multer_attribute.default, default_value, &key)?;
+                }
+                unknown => {
+                    let message = match did_you_mean(unknown, MULTER_ATTRIBUTE_KEYS) {
+                        Some(suggestion) => format!(
+                            "unknown `multer` attribute `{unknown}`, did you mean `{suggestion}`?"
+                        ),
+                        None => format!(
+                            "unknown `multer` attribute `{unknown}`, expected one of {}",
+                            MULTER_ATTRIBUTE_KEYS
+                                .iter()
+                                .map(|k| format!("`{k}`"))
+                                .collect::<Vec<_>>()
+                                .join(", ")
+                        ),
+                    };
+
+                    return Err(syn::Error::new_spanned(key, message));
                 }
-            } else {
-                return Err(lookahead.error());
             }
 
+            multer_attribute.keys.push(key);
+
             if !input.is_empty() {
                 let _: syn::Token![,] = input.parse()?;
             }
@@ -144,18 +1233,166 @@ impl Parse for MulterAttribute {
     }
 }
 
+impl MulterAttribute {
+    /// Returns an error on the first key which is not one of the `allowed` keys.
+    fn allow_only(&self, allowed: &[&str], message: &str) -> syn::Result<()> {
+        match self
+            .keys
+            .iter()
+            .find(|key| !allowed.iter().any(|a| *key == a))
+        {
+            Some(key) => Err(syn::Error::new_spanned(
+                key,
+                format!("`{key}` is not supported {message}"),
+            )),
+            None => Ok(()),
+        }
+    }
+
+    /// Returns how the field is parsed, returns an error if the attributes are incompatible.
+    fn field_kind(&self, field: &impl ToTokens) -> syn::Result<FieldKind> {
+        let nested = self.nested || self.prefix.is_some();
+
+        if self.skip {
+            if nested
+                || self.indexed
+                || self.flatten
+                || self.rename.is_some()
+                || self.with.is_some()
+                || !self.validate.is_empty()
+                || self.limit.is_some()
+            {
+                return Err(syn::Error::new_spanned(
+                    field,
+                    "`skip` can only be used with `default`",
+                ));
+            }
+
+            return Ok(FieldKind::Skip);
+        }
+
+        if self.flatten {
+            if nested
+                || self.indexed
+                || self.rename.is_some()
+                || self.default.is_some()
+                || !self.validate.is_empty()
+                || self.limit.is_some()
+            {
+                return Err(syn::Error::new_spanned(
+                    field,
+                    "`flatten` cannot be used with `rename`, `nested`, `prefix`, `indexed`, `default`, `validate` or `limit`",
+                ));
+            }
+
+            return Ok(FieldKind::Flatten);
+        }
+
+        if self.limit.is_some() && (nested || self.indexed) {
+            return Err(syn::Error::new_spanned(
+                field,
+                "`limit` cannot be used with `nested`, `prefix` or `indexed`, set the limit on their fields",
+            ));
+        }
+
+        match (nested, self.indexed) {
+            (true, true) => Err(syn::Error::new_spanned(
+                field,
+                "`indexed` cannot be used with `nested` or `prefix`",
+            )),
+            (true, false) => Ok(FieldKind::Nested),
+            (false, true) => Ok(FieldKind::Indexed),
+            (false, false) => Ok(FieldKind::Value),
+        }
+    }
+
+    /// Parses the `#[multer(...)]` attribute in the given list, if any.
+    ///
+    /// Returns an error if there is more than one `#[multer(...)]` attribute.
+    fn from_attributes(attrs: &[Attribute]) -> syn::Result<Option<MulterAttribute>> {
+        let mut multer_attr = None;
+
+        for attr in attrs {
+            if !attr.path().is_ident("multer") {
+                continue;
+            }
+
+            if multer_attr.is_some() {
+                return Err(syn::Error::new_spanned(
+                    attr,
+                    "duplicate `#[multer]` attribute, merge them into a single `#[multer(...)]`",
+                ));
+            }
+
+            multer_attr = Some(attr.parse_args()?);
+        }
+
+        Ok(multer_attr)
+    }
+}
+
+/// Sets the value of an attribute key, returns an error if the key was already set.
+fn set_once<T>(target: &mut Option<T>, value: T, key: &Ident) -> syn::Result<()> {
+    if target.is_some() {
+        return Err(syn::Error::new_spanned(
+            key,
+            format!("duplicate `multer` attribute `{key}`"),
+        ));
+    }
+
+    *target = Some(value);
+    Ok(())
+}
+
+/// Sets an attribute flag, returns an error if the flag was already set.
+fn set_flag(target: &mut bool, key: &Ident) -> syn::Result<()> {
+    if *target {
+        return Err(syn::Error::new_spanned(
+            key,
+            format!("duplicate `multer` attribute `{key}`"),
+        ));
+    }
+
+    *target = true;
+    Ok(())
+}
+
+/// Returns the closest value to `name` in the given list, if any is close enough.
+fn did_you_mean<'a>(name: &str, candidates: &[&'a str]) -> Option<&'a str> {
+    candidates
+        .iter()
+        .map(|candidate| (levenshtein(name, candidate), *candidate))
+        .filter(|(distance, _)| *distance <= 2)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+fn levenshtein(a: &str, b: &str) -> usize {
+    let b = b.chars().collect::<Vec<_>>();
+    let mut row = (0..=b.len()).collect::<Vec<_>>();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut prev = row[0];
+        row[0] = i + 1;
+
+        for (j, cb) in b.iter().enumerate() {
+            let cur = row[j + 1];
+            let cost = if ca == *cb { prev } else { prev + 1 };
+            row[j + 1] = cost.min(row[j] + 1).min(cur + 1);
+            prev = cur;
+        }
+    }
+
+    row[b.len()]
+}
+
 fn get_fields_attributes(
     fields: &Punctuated<Field, Comma>,
 ) -> syn::Result<HashMap<String, MulterAttribute>> {
     let mut attrs = HashMap::new();
 
     for field in fields {
-        for attr in &field.attrs {
-            if !attr.path().is_ident("multer") {
-                continue;
-            }
-
-            let multer_attr: MulterAttribute = attr.parse_args()?;
+        if let Some(multer_attr) = MulterAttribute::from_attributes(&field.attrs)? {
             let field_name = field.ident.as_ref().unwrap();
             attrs.insert(field_name.to_string(), multer_attr);
         }
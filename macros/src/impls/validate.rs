@@ -0,0 +1,239 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{ext::IdentExt, parse::ParseStream, Ident};
+
+const VALIDATION_KEYS: &[&str] = &["length", "range", "regex", "email", "url"];
+
+/// A validation of `#[multer(validate(...))]` checked after parsing a field.
+#[derive(Debug, Clone)]
+pub enum Validation {
+    // length(min = 1, max = 80)
+    Length {
+        min: Option<usize>,
+        max: Option<usize>,
+    },
+
+    // range(min = 18, max = 99)
+    Range {
+        min: Option<TokenStream>,
+        max: Option<TokenStream>,
+    },
+
+    // regex(path = path::to::REGEX)
+    Regex(TokenStream),
+
+    // regex = "^[a-z]+$"
+    #[cfg(feature = "regex")]
+    Pattern(String),
+
+    // email
+    Email,
+
+    // url
+    Url,
+}
+
+impl Validation {
+    /// Parses the validations inside `validate(...)`.
+    pub fn parse_list(input: ParseStream) -> syn::Result<Vec<Validation>> {
+        let content;
+        syn::parenthesized!(content in input);
+
+        let mut validations = Vec::new();
+
+        while !content.is_empty() {
+            let key: Ident = content.call(Ident::parse_any)?;
+
+            let validation = match key.to_string().as_str() {
+                "length" => {
+                    let (min, max) = parse_bounds(&content, &key)?;
+                    let min = min.map(|min| parse_usize(&min)).transpose()?;
+                    let max = max.map(|max| parse_usize(&max)).transpose()?;
+                    Validation::Length { min, max }
+                }
+                "range" => {
+                    let (min, max) = parse_bounds(&content, &key)?;
+                    Validation::Range {
+                        min: min.map(|min| quote! { #min }),
+                        max: max.map(|max| quote! { #max }),
+                    }
+                }
+                "regex" if content.peek(syn::token::Paren) => parse_regex_path(&content)?,
+                "regex" => {
+                    let _: syn::Token![=] = content.parse()?;
+                    let regex: syn::LitStr = content.parse()?;
+                    parse_regex(&regex)?
+                }
+                "email" => Validation::Email,
+                "url" => Validation::Url,
+                unknown => {
+                    return Err(syn::Error::new_spanned(
+                        &key,
+                        format!(
+                            "unknown validation `{unknown}`, expected one of: {}",
+                            VALIDATION_KEYS.join(", ")
+                        ),
+                    ))
+                }
+            };
+
+            validations.push(validation);
+
+            if !content.is_empty() {
+                let _: syn::Token![,] = content.parse()?;
+            }
+        }
+
+        Ok(validations)
+    }
+
+    /// Generates the code to check the value in `__value`,
+    /// which returns a `Result<(), FieldError>` without the name of the field.
+    pub fn to_check(&self) -> syn::Result<TokenStream> {
+        let check = match self {
+            Validation::Length { min, max } => {
+                let min = to_option(min.map(|min| quote! { #min }));
+                let max = to_option(max.map(|max| quote! { #max }));
+                quote! { ::multer_derive::validate::length(__value, #min, #max) }
+            }
+            Validation::Range { min, max } => {
+                let min = to_option(min.clone());
+                let max = to_option(max.clone());
+                quote! { ::multer_derive::validate::range(__value, #min, #max) }
+            }
+            Validation::Regex(path) => quote! {
+                ::multer_derive::validate::pattern(
+                    #path.is_match(::std::convert::AsRef::<str>::as_ref(__value))
+                )
+            },
+            #[cfg(feature = "regex")]
+            Validation::Pattern(pattern) => quote! {
+                ::multer_derive::validate::pattern({
+                    static REGEX: ::std::sync::OnceLock<::multer_derive::validate::Regex> =
+                        ::std::sync::OnceLock::new();
+
+                    REGEX
+                        .get_or_init(|| ::multer_derive::validate::Regex::new(#pattern).unwrap())
+                        .is_match(::std::convert::AsRef::<str>::as_ref(__value))
+                })
+            },
+            Validation::Email => quote! { ::multer_derive::validate::email(__value) },
+            Validation::Url => quote! { ::multer_derive::validate::url(__value) },
+        };
+
+        Ok(check)
+    }
+}
+
+/// Parses the pattern of `regex = "..."`, which requires the `regex` feature.
+///
+/// The pattern is compiled here so an invalid pattern is reported at compile time.
+fn parse_regex(regex: &syn::LitStr) -> syn::Result<Validation> {
+    #[cfg(feature = "regex")]
+    {
+        let pattern = regex.value();
+        match regex::Regex::new(&pattern) {
+            Ok(_) => Ok(Validation::Pattern(pattern)),
+            Err(err) => Err(syn::Error::new_spanned(
+                regex,
+                format!("invalid regex pattern: {err}"),
+            )),
+        }
+    }
+
+    #[cfg(not(feature = "regex"))]
+    Err(syn::Error::new_spanned(
+        regex,
+        "enable the `regex` feature to use a pattern, \
+        or use `regex(path = path::to::REGEX)` with a `Regex` value",
+    ))
+}
+
+/// Parses `regex(path = path::to::REGEX)`, a path to a value with an `is_match` method.
+fn parse_regex_path(input: ParseStream) -> syn::Result<Validation> {
+    let content;
+    syn::parenthesized!(content in input);
+
+    let key: Ident = content.call(Ident::parse_any)?;
+    if key != "path" {
+        return Err(syn::Error::new_spanned(
+            &key,
+            format!("unknown `regex` option `{key}`, expected `path`"),
+        ));
+    }
+
+    let _: syn::Token![=] = content.parse()?;
+    let path: syn::Path = content.parse()?;
+
+    if !content.is_empty() {
+        return Err(content.error("expected `)` after the path of the `regex`"));
+    }
+
+    Ok(Validation::Regex(quote! { #path }))
+}
+
+/// Parses `(min = ..., max = ...)`, at least one of the bounds is required.
+fn parse_bounds(
+    input: ParseStream,
+    key: &Ident,
+) -> syn::Result<(Option<syn::Expr>, Option<syn::Expr>)> {
+    let content;
+    syn::parenthesized!(content in input);
+
+    let mut min = None;
+    let mut max = None;
+
+    while !content.is_empty() {
+        let bound: Ident = content.call(Ident::parse_any)?;
+        let _: syn::Token![=] = content.parse()?;
+        let value: syn::Expr = content.parse()?;
+
+        let target = match bound.to_string().as_str() {
+            "min" => &mut min,
+            "max" => &mut max,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    &bound,
+                    format!("unknown `{key}` bound `{bound}`, expected `min` or `max`"),
+                ))
+            }
+        };
+
+        if target.replace(value).is_some() {
+            return Err(syn::Error::new_spanned(
+                &bound,
+                format!("duplicate `{key}` bound `{bound}`"),
+            ));
+        }
+
+        if !content.is_empty() {
+            let _: syn::Token![,] = content.parse()?;
+        }
+    }
+
+    if min.is_none() && max.is_none() {
+        return Err(syn::Error::new_spanned(
+            key,
+            format!("`{key}` requires a `min` or `max` bound"),
+        ));
+    }
+
+    Ok((min, max))
+}
+
+fn parse_usize(expr: &syn::Expr) -> syn::Result<usize> {
+    match expr {
+        syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Int(lit),
+            ..
+        }) => lit.base10_parse(),
+        _ => Err(syn::Error::new_spanned(expr, "expected an integer literal")),
+    }
+}
+
+fn to_option(value: Option<TokenStream>) -> TokenStream {
+    match value {
+        Some(value) => quote! { ::std::option::Option::Some(#value) },
+        None => quote! { ::std::option::Option::None },
+    }
+}
@@ -4,7 +4,7 @@ use syn::DeriveInput;
 mod impls;
 
 /// Provide an implementation of `FromMultipart` for construct types from [`multer::Multipart`].
-/// 
+///
 /// [`multer::Multipart`]: https://docs.rs/multer/2.1.0/multer/struct.Multipart.html
 #[proc_macro_derive(FromMultipart, attributes(multer))]
 pub fn derive_from_multipart(input: TokenStream) -> TokenStream {
@@ -15,3 +15,31 @@ pub fn derive_from_multipart(input: TokenStream) -> TokenStream {
         Err(err) => err.into_compile_error().into(),
     }
 }
+
+/// Provide an implementation of `FromMultipartAsync` for structs with named fields.
+///
+/// The fields are parsed with `FromFieldAsync` as they arrive, in any order,
+/// and the missing fields are reported once the whole form was read.
+#[proc_macro_derive(FromMultipartAsync, attributes(multer))]
+pub fn derive_from_multipart_async(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as DeriveInput);
+
+    match impls::derive_from_multipart_async(input) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.into_compile_error().into(),
+    }
+}
+
+/// Provide an implementation of `FromMultipartField` for enums with unit variants.
+///
+/// Each variant is matched against the text of the form field, use `#[multer(rename = "...")]`
+/// to match a variant using other value.
+#[proc_macro_derive(FromMultipartField, attributes(multer))]
+pub fn derive_from_multipart_field(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as DeriveInput);
+
+    match impls::derive_from_multipart_field(input) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.into_compile_error().into(),
+    }
+}
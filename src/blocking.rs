@@ -0,0 +1,131 @@
+use crate::MultipartForm;
+use futures_core::Stream;
+use multer::{bytes::Bytes, Multipart};
+use std::{
+    future::Future,
+    io::Read,
+    pin::{pin, Pin},
+    sync::Arc,
+    task::{Context, Poll, Wake},
+    thread::Thread,
+};
+
+/// The size of the chunks read by [`MultipartForm::from_reader`].
+const CHUNK_SIZE: usize = 8 * 1024;
+
+impl MultipartForm {
+    /// Creates a multipart form from the bytes of a `multipart/form-data` body with the given boundary,
+    /// without an async runtime.
+    pub fn from_bytes(bytes: &[u8], boundary: &str) -> multer::Result<MultipartForm> {
+        MultipartForm::from_reader(bytes, boundary)
+    }
+
+    /// Creates a multipart form by reading a `multipart/form-data` body with the given boundary,
+    /// blocking the current thread while the reader is read.
+    pub fn from_reader<R>(reader: R, boundary: &str) -> multer::Result<MultipartForm>
+    where
+        R: Read + Unpin + Send,
+    {
+        let multipart = Multipart::new(ReadStream(reader), boundary);
+        block_on(MultipartForm::with_multipart(multipart))
+    }
+}
+
+/// A stream over the chunks of a blocking reader.
+struct ReadStream<R>(R);
+
+impl<R: Read + Unpin> Stream for ReadStream<R> {
+    type Item = std::io::Result<Bytes>;
+
+    fn poll_next(mut self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut buf = vec![0; CHUNK_SIZE];
+
+        let result = loop {
+            match self.0.read(&mut buf) {
+                Ok(0) => break None,
+                Ok(len) => {
+                    buf.truncate(len);
+                    break Some(Ok(Bytes::from(buf)));
+                }
+                Err(err) if err.kind() == std::io::ErrorKind::Interrupted => continue,
+                Err(err) => break Some(Err(err)),
+            }
+        };
+
+        Poll::Ready(result)
+    }
+}
+
+/// Wakes the thread blocked on a future.
+struct ThreadWaker(Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+/// Runs the future in the current thread until completes.
+fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+    let waker = Arc::new(ThreadWaker(std::thread::current())).into();
+    let mut cx = Context::from_waker(&waker);
+
+    loop {
+        match future.as_mut().poll(&mut cx) {
+            Poll::Ready(output) => return output,
+            Poll::Pending => std::thread::park(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::MultipartForm;
+    use std::io::Read;
+
+    /// A reader which returns a single byte on each read.
+    struct ByteReader<'a>(&'a [u8]);
+
+    impl Read for ByteReader<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            Read::take(&mut self.0, 1).read(buf)
+        }
+    }
+
+    const FORM: &str = "--MyBoundary\r\n\
+        Content-Disposition: form-data; name=\"name\"\r\n\
+        \r\n\
+        John Doe\r\n\
+        --MyBoundary\r\n\
+        Content-Disposition: form-data; name=\"file\"; filename=\"example.txt\"\r\n\
+        Content-Type: text/plain\r\n\
+        \r\n\
+        This is an example file.\r\n\
+        --MyBoundary--\r\n";
+
+    #[test]
+    fn from_bytes_test() {
+        let form = MultipartForm::from_bytes(FORM.as_bytes(), "MyBoundary").unwrap();
+
+        assert_eq!(form.len(), 2);
+        assert_eq!(form[0].name(), Some("name"));
+        assert_eq!(form[0].text(), "John Doe");
+        assert_eq!(form[1].file_name(), Some("example.txt"));
+        assert_eq!(form[1].text(), "This is an example file.");
+    }
+
+    #[test]
+    fn from_reader_test() {
+        let form = MultipartForm::from_reader(ByteReader(FORM.as_bytes()), "MyBoundary").unwrap();
+
+        assert_eq!(form.len(), 2);
+        assert_eq!(form[1].text(), "This is an example file.");
+    }
+
+    #[test]
+    fn from_bytes_invalid_test() {
+        let form = FORM.replace("--MyBoundary--", "");
+        assert!(MultipartForm::from_bytes(form.as_bytes(), "MyBoundary").is_err());
+    }
+}
@@ -0,0 +1,335 @@
+use crate::{
+    error::{Error, FieldError, FieldErrorKind},
+    Notation,
+};
+use std::collections::HashMap;
+
+/// The segment that matches any index in the names of the field sizes, like `items[*][name]`.
+pub(crate) const ANY_INDEX: &str = "*";
+
+/// Limits applied while reading a multipart form with [`MultipartForm::with_constraints`].
+///
+/// The limits are checked while the fields are streamed, so a form exceeding a limit
+/// is rejected before buffering the rest of the body.
+///
+/// [`MultipartForm::with_constraints`]: crate::MultipartForm::with_constraints
+#[derive(Debug, Clone, Default)]
+pub struct FormConstraints {
+    max_total_size: Option<u64>,
+    max_field_size: Option<u64>,
+    max_text_size: Option<u64>,
+    field_sizes: HashMap<String, u64>,
+    max_files: Option<usize>,
+    max_fields: Option<usize>,
+    allowed_fields: Option<Vec<String>>,
+}
+
+impl FormConstraints {
+    /// Constructs constraints without any limit.
+    pub fn new() -> Self {
+        FormConstraints::default()
+    }
+
+    /// Sets the max size in bytes of the whole form.
+    pub fn max_total_size(mut self, size: u64) -> Self {
+        self.max_total_size = Some(size);
+        self
+    }
+
+    /// Sets the max size in bytes of each field.
+    pub fn max_field_size(mut self, size: u64) -> Self {
+        self.max_field_size = Some(size);
+        self
+    }
+
+    /// Sets the max size in bytes of each text field, files use the `max_field_size`.
+    pub fn max_text_size(mut self, size: u64) -> Self {
+        self.max_text_size = Some(size);
+        self
+    }
+
+    /// Sets the max size in bytes of the field with the given name, this takes precedence over other sizes.
+    ///
+    /// Use `*` to match any index of a list, like `items[*][name]`.
+    pub fn max_size_for(mut self, name: impl Into<String>, size: u64) -> Self {
+        self.field_sizes.insert(name.into(), size);
+        self
+    }
+
+    /// Sets the max number of files in the form.
+    pub fn max_files(mut self, count: usize) -> Self {
+        self.max_files = Some(count);
+        self
+    }
+
+    /// Sets the max number of fields in the form, including files.
+    pub fn max_fields(mut self, count: usize) -> Self {
+        self.max_fields = Some(count);
+        self
+    }
+
+    /// Sets the only field names allowed in the form.
+    pub fn allowed_fields<I, S>(mut self, names: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.allowed_fields = Some(names.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Adds the limits of `other`, the limits already set take precedence.
+    pub fn merge(mut self, other: FormConstraints) -> Self {
+        self.max_total_size = self.max_total_size.or(other.max_total_size);
+        self.max_field_size = self.max_field_size.or(other.max_field_size);
+        self.max_text_size = self.max_text_size.or(other.max_text_size);
+        self.max_files = self.max_files.or(other.max_files);
+        self.max_fields = self.max_fields.or(other.max_fields);
+
+        for (name, size) in other.field_sizes {
+            self.field_sizes.entry(name).or_insert(size);
+        }
+
+        self.allowed_fields = match (self.allowed_fields, other.allowed_fields) {
+            (Some(mut names), Some(other)) => {
+                names.extend(other);
+                Some(names)
+            }
+            (names, other) => names.or(other),
+        };
+
+        self
+    }
+
+    /// Joins the names of the fields with the given prefix, used for the limits of nested forms.
+    pub fn prefixed(mut self, prefix: &str, notation: Notation) -> Self {
+        self.field_sizes = self
+            .field_sizes
+            .into_iter()
+            .map(|(name, size)| (join_path(notation, prefix, &name), size))
+            .collect();
+
+        self.allowed_fields = self.allowed_fields.map(|names| {
+            names
+                .iter()
+                .map(|name| join_path(notation, prefix, name))
+                .collect()
+        });
+
+        self
+    }
+
+    /// Applies the limits to the items of a list, like `[*][name]` for the `name` field of any index.
+    ///
+    /// The names are then joined to the name of the list with [`FormConstraints::prefixed`].
+    pub fn indexed(self, notation: Notation) -> Self {
+        self.prefixed(ANY_INDEX, notation)
+    }
+
+    /// Returns the max size in bytes of the field with the given name, if any.
+    pub fn max_size(&self, name: Option<&str>, is_file: bool) -> Option<u64> {
+        if let Some(name) = name {
+            let size = self.field_sizes.get(name).or_else(|| {
+                self.field_sizes
+                    .iter()
+                    .find(|(pattern, _)| matches_any_index(pattern, name))
+                    .map(|(_, size)| size)
+            });
+
+            if let Some(size) = size {
+                return Some(*size);
+            }
+        }
+
+        match is_file {
+            true => self.max_field_size,
+            false => self.max_text_size.or(self.max_field_size),
+        }
+    }
+
+    /// Checks a new field can be added to a form with the given number of fields and files.
+    pub(crate) fn check_field(
+        &self,
+        name: Option<&str>,
+        is_file: bool,
+        fields: usize,
+        files: usize,
+    ) -> Result<(), Error> {
+        let field_error = |kind| {
+            let error = FieldError::new(kind);
+            match name {
+                Some(name) => error.with_name(name).with_path(name),
+                None => error,
+            }
+        };
+
+        if let Some(allowed_fields) = &self.allowed_fields {
+            if !name.is_some_and(|name| allowed_fields.iter().any(|allowed| allowed == name)) {
+                return Err(field_error(FieldErrorKind::NotAllowed).into());
+            }
+        }
+
+        if let Some(max_fields) = self.max_fields.filter(|max| fields >= *max) {
+            return Err(field_error(FieldErrorKind::TooMany)
+                .with_constraint("max_fields", max_fields)
+                .into());
+        }
+
+        if let Some(max_files) = self.max_files.filter(|max| is_file && files >= *max) {
+            return Err(field_error(FieldErrorKind::TooMany)
+                .with_constraint("max_files", max_files)
+                .into());
+        }
+
+        Ok(())
+    }
+
+    /// Checks the size of the form after reading a chunk.
+    pub(crate) fn check_size(
+        &self,
+        name: Option<&str>,
+        max_size: Option<u64>,
+        field_size: u64,
+        total_size: u64,
+    ) -> Result<(), Error> {
+        if let Some(limit) = self.max_total_size.filter(|max| total_size > *max) {
+            return Err(multer::Error::StreamSizeExceeded { limit }.into());
+        }
+
+        if let Some(max_size) = max_size.filter(|max| field_size > *max) {
+            let mut error =
+                FieldError::new(FieldErrorKind::TooLarge).with_constraint("max_size", max_size);
+
+            if let Some(name) = name {
+                error = error.with_name(name).with_path(name);
+            }
+
+            return Err(error.into());
+        }
+
+        Ok(())
+    }
+}
+
+/// Joins the path of a field in a nested form with the prefix of the form,
+/// `address[street]` is joined as `user[address][street]`.
+fn join_path(notation: Notation, prefix: &str, path: &str) -> String {
+    match path.find('[') {
+        Some(end) if end > 0 && notation == Notation::Brackets => {
+            let (name, rest) = path.split_at(end);
+            format!("{prefix}[{name}]{rest}")
+        }
+        _ => notation.join(prefix, path),
+    }
+}
+
+/// Returns `true` if the field name matches a pattern with `*` indices, like `items[*][name]` with `items[0][name]`.
+fn matches_any_index(pattern: &str, name: &str) -> bool {
+    if !pattern.contains(ANY_INDEX) {
+        return false;
+    }
+
+    fn segments(path: &str) -> Vec<&str> {
+        path.split(['[', ']', '.'])
+            .filter(|segment| !segment.is_empty())
+            .collect()
+    }
+
+    let patterns = segments(pattern);
+    let names = segments(name);
+
+    patterns.len() == names.len()
+        && patterns.iter().zip(names).all(|(pattern, name)| {
+            *pattern == name || (*pattern == ANY_INDEX && name.bytes().all(|b| b.is_ascii_digit()))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FormConstraints;
+    use crate::Notation;
+
+    #[test]
+    fn max_size_test() {
+        let constraints = FormConstraints::new()
+            .max_field_size(100)
+            .max_text_size(10)
+            .max_size_for("avatar", 50);
+
+        assert_eq!(constraints.max_size(Some("avatar"), true), Some(50));
+        assert_eq!(constraints.max_size(Some("photo"), true), Some(100));
+        assert_eq!(constraints.max_size(Some("bio"), false), Some(10));
+        assert_eq!(FormConstraints::new().max_size(Some("bio"), false), None);
+    }
+
+    #[test]
+    fn prefixed_test() {
+        let constraints = FormConstraints::new()
+            .max_size_for("avatar", 50)
+            .prefixed("user", Notation::Brackets);
+
+        assert_eq!(constraints.max_size(Some("user[avatar]"), true), Some(50));
+        assert_eq!(constraints.max_size(Some("avatar"), true), None);
+
+        let constraints = constraints.prefixed("account", Notation::Brackets);
+        assert_eq!(
+            constraints.max_size(Some("account[user][avatar]"), true),
+            Some(50)
+        );
+    }
+
+    #[test]
+    fn any_index_test() {
+        let constraints = FormConstraints::new()
+            .max_size_for("items[*][name]", 4)
+            .max_size_for("items[1][name]", 8);
+
+        assert_eq!(constraints.max_size(Some("items[0][name]"), false), Some(4));
+        assert_eq!(
+            constraints.max_size(Some("items[12][name]"), false),
+            Some(4)
+        );
+        assert_eq!(constraints.max_size(Some("items[1][name]"), false), Some(8));
+        assert_eq!(constraints.max_size(Some("items[a][name]"), false), None);
+        assert_eq!(constraints.max_size(Some("items[0]"), false), None);
+        assert_eq!(constraints.max_size(Some("items[0][name][x]"), false), None);
+    }
+
+    #[test]
+    fn indexed_test() {
+        let constraints = FormConstraints::new()
+            .max_size_for("name", 4)
+            .indexed(Notation::Dots)
+            .prefixed("items", Notation::Dots);
+
+        assert!(constraints.field_sizes.contains_key("items.*.name"));
+        assert_eq!(constraints.max_size(Some("items.0.name"), false), Some(4));
+
+        let constraints = FormConstraints::new()
+            .max_size_for("name", 4)
+            .indexed(Notation::Brackets)
+            .prefixed("items", Notation::Brackets);
+
+        assert!(constraints.field_sizes.contains_key("items[*][name]"));
+        assert_eq!(constraints.max_size(Some("items[3][name]"), false), Some(4));
+    }
+
+    #[test]
+    fn merge_test() {
+        let constraints = FormConstraints::new()
+            .max_total_size(1000)
+            .max_size_for("avatar", 50)
+            .merge(
+                FormConstraints::new()
+                    .max_total_size(10)
+                    .max_files(2)
+                    .max_size_for("avatar", 10)
+                    .max_size_for("bio", 20),
+            );
+
+        assert_eq!(constraints.max_total_size, Some(1000));
+        assert_eq!(constraints.max_files, Some(2));
+        assert_eq!(constraints.max_size(Some("avatar"), true), Some(50));
+        assert_eq!(constraints.max_size(Some("bio"), false), Some(20));
+    }
+}
@@ -1,3 +1,5 @@
+use std::{collections::BTreeMap, fmt::Display};
+
 /// An error that ocurred while processing a multipart.
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
@@ -5,6 +7,14 @@ pub enum Error {
     #[error(transparent)]
     MultipartError(multer::Error),
 
+    /// An error ocurred while parsing a form field.
+    #[error(transparent)]
+    Field(FieldError),
+
+    /// Multiple form fields failed to parse, returned by types using `#[multer(collect_errors)]`.
+    #[error(transparent)]
+    Multiple(Errors),
+
     /// Other error that ocurred.
     #[error(transparent)]
     Other(Box<dyn std::error::Error + Send + Sync>),
@@ -20,6 +30,65 @@ impl Error {
     pub fn from_multer(error: multer::Error) -> Self {
         Error::MultipartError(error)
     }
+
+    /// Returns the field error, if this error ocurred while parsing a form field.
+    pub fn as_field_error(&self) -> Option<&FieldError> {
+        match self {
+            Error::Field(error) => Some(error),
+            _ => None,
+        }
+    }
+
+    /// Returns all the field errors, if multiple form fields failed to parse.
+    pub fn as_errors(&self) -> Option<&Errors> {
+        match self {
+            Error::Multiple(errors) => Some(errors),
+            _ => None,
+        }
+    }
+
+    /// Converts this error to a [`FieldError`], other errors are converted to a field error of the given kind.
+    ///
+    /// If this error contains multiple field errors only the first one is returned.
+    pub fn into_field_error(self, kind: FieldErrorKind) -> FieldError {
+        match self {
+            Error::Field(error) => error,
+            Error::Multiple(errors) => errors
+                .0
+                .into_iter()
+                .next()
+                .unwrap_or_else(|| FieldError::new(kind)),
+            Error::MultipartError(error) => FieldError::new(kind).with_source(error),
+            Error::Other(error) => FieldError::new(kind).with_source(error),
+        }
+    }
+
+    /// Attaches this error to the form field with the given name, path and index,
+    /// only the values that are not already set are filled. The errors of `multer` are returned as is.
+    ///
+    /// Used by the derived implementations to report which field failed to parse.
+    #[doc(hidden)]
+    pub fn for_field(self, name: &str, path: &str, index: Option<usize>) -> Error {
+        let scope = |error: FieldError| {
+            let mut scoped = FieldError::new(error.kind())
+                .with_name(name)
+                .with_path(path);
+
+            if let Some(index) = index {
+                scoped = scoped.with_index(index);
+            }
+
+            error.or(scoped)
+        };
+
+        match self {
+            Error::MultipartError(_) => self,
+            Error::Multiple(errors) => {
+                Error::Multiple(Errors(errors.0.into_iter().map(scope).collect()))
+            }
+            error => Error::Field(scope(error.into_field_error(FieldErrorKind::Parse))),
+        }
+    }
 }
 
 impl From<multer::Error> for Error {
@@ -27,3 +96,348 @@ impl From<multer::Error> for Error {
         Error::from_multer(error)
     }
 }
+
+impl From<FieldError> for Error {
+    fn from(error: FieldError) -> Self {
+        Error::Field(error)
+    }
+}
+
+impl From<Errors> for Error {
+    fn from(errors: Errors) -> Self {
+        Error::Multiple(errors)
+    }
+}
+
+/// The kind of error that ocurred while parsing a form field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FieldErrorKind {
+    /// The field was not found in the form.
+    Missing,
+
+    /// The value of the field is invalid.
+    Parse,
+
+    /// The value of the field does not satisfy a validation.
+    Validation,
+
+    /// The field exceeded a size limit.
+    TooLarge,
+
+    /// The content type of the field was not expected.
+    UnexpectedContentType,
+
+    /// The field was expected to be a file.
+    NotAFile,
+
+    /// The field was sent more than once.
+    Duplicate,
+
+    /// The name of the field is not allowed in the form.
+    NotAllowed,
+
+    /// The form exceeded the number of fields or files allowed.
+    TooMany,
+}
+
+impl FieldErrorKind {
+    /// Returns a short code for this kind of error, like `missing` or `too_large`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FieldErrorKind::Missing => "missing",
+            FieldErrorKind::Parse => "parse",
+            FieldErrorKind::Validation => "validation",
+            FieldErrorKind::TooLarge => "too_large",
+            FieldErrorKind::UnexpectedContentType => "unexpected_content_type",
+            FieldErrorKind::NotAFile => "not_a_file",
+            FieldErrorKind::Duplicate => "duplicate",
+            FieldErrorKind::NotAllowed => "not_allowed",
+            FieldErrorKind::TooMany => "too_many",
+        }
+    }
+}
+
+/// The value of a constraint of a [`FieldError`], like the `max` length of a field.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConstraintValue {
+    /// An unsigned integer, like a size limit.
+    UInt(u64),
+
+    /// A signed integer.
+    Int(i64),
+
+    /// A floating point number.
+    Float(f64),
+
+    /// A boolean.
+    Bool(bool),
+
+    /// A text, like a pattern or a format.
+    Text(String),
+}
+
+impl Display for ConstraintValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConstraintValue::UInt(value) => write!(f, "{value}"),
+            ConstraintValue::Int(value) => write!(f, "{value}"),
+            ConstraintValue::Float(value) => write!(f, "{value}"),
+            ConstraintValue::Bool(value) => write!(f, "{value}"),
+            ConstraintValue::Text(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+macro_rules! impl_constraint_value_from {
+    ($variant:ident($inner:ty): $($ty:ty),*) => {
+        $(
+            impl From<$ty> for ConstraintValue {
+                fn from(value: $ty) -> Self {
+                    ConstraintValue::$variant(value as $inner)
+                }
+            }
+        )*
+    };
+}
+
+impl_constraint_value_from!(UInt(u64): u8, u16, u32, u64, usize);
+impl_constraint_value_from!(Int(i64): i8, i16, i32, i64, isize);
+impl_constraint_value_from!(Float(f64): f32, f64);
+
+impl From<bool> for ConstraintValue {
+    fn from(value: bool) -> Self {
+        ConstraintValue::Bool(value)
+    }
+}
+
+impl From<&str> for ConstraintValue {
+    fn from(value: &str) -> Self {
+        ConstraintValue::Text(value.to_owned())
+    }
+}
+
+impl From<String> for ConstraintValue {
+    fn from(value: String) -> Self {
+        ConstraintValue::Text(value)
+    }
+}
+
+/// An error that ocurred while parsing a form field.
+#[derive(Debug)]
+pub struct FieldError {
+    kind: FieldErrorKind,
+    name: Option<String>,
+    path: Option<String>,
+    index: Option<usize>,
+    constraints: Vec<(String, ConstraintValue)>,
+    source: Option<Box<dyn std::error::Error + Send + Sync>>,
+}
+
+impl FieldError {
+    /// Constructs a new field error of the given kind.
+    pub fn new(kind: FieldErrorKind) -> Self {
+        FieldError {
+            kind,
+            name: None,
+            path: None,
+            index: None,
+            constraints: Vec::new(),
+            source: None,
+        }
+    }
+
+    /// Sets the name of the field.
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Sets the full path of the field in the form, like `address[street]`.
+    pub fn with_path(mut self, path: impl Into<String>) -> Self {
+        self.path = Some(path.into());
+        self
+    }
+
+    /// Sets the index of the field in the form.
+    pub fn with_index(mut self, index: usize) -> Self {
+        self.index = Some(index);
+        self
+    }
+
+    /// Adds a constraint the value of the field must satisfy, like a `max` length.
+    pub fn with_constraint(
+        mut self,
+        name: impl Into<String>,
+        value: impl Into<ConstraintValue>,
+    ) -> Self {
+        self.constraints.push((name.into(), value.into()));
+        self
+    }
+
+    /// Sets the error that caused this error.
+    pub fn with_source(
+        mut self,
+        source: impl Into<Box<dyn std::error::Error + Send + Sync>>,
+    ) -> Self {
+        self.source = Some(source.into());
+        self
+    }
+
+    /// Returns the kind of this error.
+    pub fn kind(&self) -> FieldErrorKind {
+        self.kind
+    }
+
+    /// Returns the name of the field, if known.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Returns the full path of the field in the form, if known.
+    pub fn path(&self) -> Option<&str> {
+        self.path.as_deref()
+    }
+
+    /// Returns the index of the field in the form, if the field was found.
+    pub fn index(&self) -> Option<usize> {
+        self.index
+    }
+
+    /// Returns the constraints the value of the field must satisfy.
+    pub fn constraints(&self) -> impl Iterator<Item = (&str, &ConstraintValue)> {
+        self.constraints
+            .iter()
+            .map(|(name, value)| (name.as_str(), value))
+    }
+
+    /// Fills the name, path and index of this error that are not set using the values of `other`.
+    pub fn or(mut self, other: FieldError) -> Self {
+        self.name = self.name.or(other.name);
+        self.path = self.path.or(other.path);
+        self.index = self.index.or(other.index);
+        self
+    }
+}
+
+impl Display for FieldError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.path.as_deref().or(self.name.as_deref()) {
+            Some(path) => write!(f, "`{path}` form field")?,
+            None => write!(f, "form field")?,
+        }
+
+        match self.kind {
+            FieldErrorKind::Missing => write!(f, " was not found")?,
+            FieldErrorKind::Parse | FieldErrorKind::Validation => write!(f, " is invalid")?,
+            FieldErrorKind::TooLarge => write!(f, " is too large")?,
+            FieldErrorKind::UnexpectedContentType => write!(f, " has an unexpected content type")?,
+            FieldErrorKind::NotAFile => write!(f, " is not a file")?,
+            FieldErrorKind::Duplicate => write!(f, " was sent more than once")?,
+            FieldErrorKind::NotAllowed => write!(f, " is not allowed")?,
+            FieldErrorKind::TooMany => write!(f, " exceeds the number of fields allowed")?,
+        }
+
+        match &self.source {
+            Some(source) => write!(f, ": {source}"),
+            None => Ok(()),
+        }
+    }
+}
+
+impl std::error::Error for FieldError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.source
+            .as_deref()
+            .map(|source| source as &(dyn std::error::Error + 'static))
+    }
+}
+
+/// A collection of all the errors that ocurred while parsing a form.
+#[derive(Debug, Default)]
+pub struct Errors(Vec<FieldError>);
+
+impl Errors {
+    /// Constructs an empty collection of errors.
+    pub fn new() -> Self {
+        Errors::default()
+    }
+
+    /// Adds an error, the errors of an [`Error::Multiple`] are added individually.
+    ///
+    /// Errors that are not field errors are added as a [`FieldErrorKind::Parse`] error without a path.
+    pub fn push(&mut self, error: impl Into<Error>) {
+        match error.into() {
+            Error::Multiple(errors) => self.0.extend(errors.0),
+            error => self.0.push(error.into_field_error(FieldErrorKind::Parse)),
+        }
+    }
+
+    /// Returns the number of errors.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if there is no errors.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns an iterator over all the errors.
+    pub fn iter(&self) -> std::slice::Iter<'_, FieldError> {
+        self.0.iter()
+    }
+
+    /// Returns an iterator over the errors of the field with the given path, like `address[street]`.
+    pub fn get<'a>(&'a self, path: &'a str) -> impl Iterator<Item = &'a FieldError> + 'a {
+        self.0
+            .iter()
+            .filter(move |error| error.path() == Some(path))
+    }
+
+    /// Returns the errors grouped by the path of the field, errors without a path are grouped under `""`.
+    pub fn by_path(&self) -> BTreeMap<&str, Vec<&FieldError>> {
+        let mut map = BTreeMap::<&str, Vec<&FieldError>>::new();
+
+        for error in &self.0 {
+            map.entry(error.path().unwrap_or_default())
+                .or_default()
+                .push(error);
+        }
+
+        map
+    }
+}
+
+impl Display for Errors {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, error) in self.0.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+
+            write!(f, "{error}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for Errors {}
+
+impl IntoIterator for Errors {
+    type Item = FieldError;
+    type IntoIter = std::vec::IntoIter<FieldError>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Errors {
+    type Item = &'a FieldError;
+    type IntoIter = std::slice::Iter<'a, FieldError>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
@@ -20,15 +20,11 @@ impl FromMultipart for FileCollection {
     ) -> Result<Self, Error> {
         let mut files = vec![];
 
-        for field in multipart.fields() {
+        for field in multipart.fields_named(ctx.field_name) {
             if field.file_name().is_none() {
                 continue;
             }
 
-            if ctx.field_name.is_some() && ctx.field_name != field.name() {
-                continue;
-            }
-
             files.push(FormFile::from_field(field)?);
         }
 
@@ -1,44 +1,88 @@
 use crate::{
-    error::Error, from_multipart_field::FromMultipartField, multipart_form::MultipartField,
+    error::{Error, FieldError, FieldErrorKind},
+    from_multipart_field::FromMultipartField,
+    multipart_form::MultipartField,
+    storage::FieldData,
 };
 use http::HeaderMap;
 use mime::Mime;
 use multer::bytes::Bytes;
+use std::{
+    io::Read,
+    path::{Path, PathBuf},
+};
 
 /// Represents a file sent in a form.
 #[derive(Debug, Clone)]
 pub struct FormFile {
-    bytes: Bytes,
-    headers: HeaderMap,
-    content_type: Mime,
-    name: String,
-    file_name: String,
+    data: FieldData,
+    metadata: FieldMetadata,
 }
 
 impl FormFile {
     /// Returns the bytes of this file.
+    ///
+    /// If the file is stored in a temporary file, the file is read into memory the first time.
+    ///
+    /// # Panics
+    /// If the temporary file cannot be read, use [`FormFile::try_bytes`] to handle the errors.
     pub fn bytes(&self) -> &Bytes {
-        &self.bytes
+        self.try_bytes().expect("failed to read temporary file")
+    }
+
+    /// Returns the bytes of this file, or an error if the temporary file cannot be read.
+    pub fn try_bytes(&self) -> std::io::Result<&Bytes> {
+        self.data.try_bytes()
+    }
+
+    /// Returns the size in bytes of this file.
+    pub fn size(&self) -> u64 {
+        self.data.len()
+    }
+
+    /// Returns the path of the temporary file, if the file was stored in a temporary file.
+    ///
+    /// The temporary file is removed when the form and all the files referencing it are dropped,
+    /// unless it was moved with [`FormFile::persist`], then the new path is returned.
+    pub fn path(&self) -> Option<&Path> {
+        self.data.path()
+    }
+
+    /// Returns a reader over the contents of this file.
+    pub fn reader(&self) -> std::io::Result<impl Read + '_> {
+        self.data.reader()
+    }
+
+    /// Moves this file to the given path.
+    ///
+    /// A temporary file is renamed, even if the form or other values still reference it,
+    /// they read the file from the new path which is no longer removed on drop.
+    /// The contents are copied if the file is in memory, the rename fails like across file systems,
+    /// or the file was already moved.
+    pub fn persist(self, path: impl AsRef<Path>) -> std::io::Result<PathBuf> {
+        let path = path.as_ref();
+        self.data.persist(path)?;
+        Ok(path.to_owned())
     }
 
     /// Return the name of the form field
     pub fn name(&self) -> &str {
-        &self.name
+        self.metadata.name()
     }
 
     /// Returns the file name.
     pub fn file_name(&self) -> &str {
-        &self.file_name
+        self.metadata.file_name().unwrap_or_default()
     }
 
     /// Returns the headers of the file.
     pub fn headers(&self) -> &HeaderMap {
-        &self.headers
+        self.metadata.headers()
     }
 
     /// Returns the content type of this file.
     pub fn content_type(&self) -> &Mime {
-        &self.content_type
+        self.metadata.content_type()
     }
 }
 
@@ -46,27 +90,72 @@ impl FromMultipartField for FormFile {
     fn from_field(field: &MultipartField) -> Result<Self, Error> {
         let name = field
             .name()
-            .ok_or_else(|| Error::new("field does not have a name"))?
-            .to_owned();
+            .ok_or_else(|| Error::new("field does not have a name"))?;
 
-        let file_name = field
-            .file_name()
-            .ok_or_else(|| Error::new("file does not have a name"))?
-            .to_owned();
+        if field.file_name().is_none() {
+            return Err(FieldError::new(FieldErrorKind::NotAFile)
+                .with_name(name)
+                .with_path(name)
+                .with_index(field.index())
+                .into());
+        }
 
-        let headers = field.headers().clone();
-        let bytes = field.bytes().clone();
-        let content_type = field
-            .content_type()
-            .cloned()
-            .unwrap_or(mime::APPLICATION_OCTET_STREAM);
+        let metadata = FieldMetadata::new(
+            field.name(),
+            field.file_name(),
+            field.content_type(),
+            field.headers(),
+        );
 
         Ok(FormFile {
-            bytes,
-            headers,
-            name,
-            file_name,
-            content_type,
+            data: field.data().clone(),
+            metadata,
         })
     }
 }
+
+/// The name, file name, content type and headers of a field, shared by [`FormFile`] and [`FieldStream`].
+///
+/// [`FieldStream`]: crate::FieldStream
+#[derive(Debug, Clone)]
+pub(crate) struct FieldMetadata {
+    name: String,
+    file_name: Option<String>,
+    content_type: Mime,
+    headers: HeaderMap,
+}
+
+impl FieldMetadata {
+    /// Collects the metadata of a field, the content type is `application/octet-stream` if was not sent.
+    pub fn new(
+        name: Option<&str>,
+        file_name: Option<&str>,
+        content_type: Option<&Mime>,
+        headers: &HeaderMap,
+    ) -> Self {
+        FieldMetadata {
+            name: name.unwrap_or_default().to_owned(),
+            file_name: file_name.map(ToOwned::to_owned),
+            content_type: content_type
+                .cloned()
+                .unwrap_or(mime::APPLICATION_OCTET_STREAM),
+            headers: headers.clone(),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn file_name(&self) -> Option<&str> {
+        self.file_name.as_deref()
+    }
+
+    pub fn content_type(&self) -> &Mime {
+        &self.content_type
+    }
+
+    pub fn headers(&self) -> &HeaderMap {
+        &self.headers
+    }
+}
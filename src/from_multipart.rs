@@ -1,7 +1,11 @@
 use crate::{
-    error::Error, from_multipart_field::FromMultipartField, multipart_form::MultipartForm,
+    constraints::FormConstraints,
+    error::{Error, Errors, FieldError, FieldErrorKind},
+    from_multipart_field::FromMultipartField,
+    multipart_form::MultipartForm,
 };
 use std::{
+    borrow::Cow,
     collections::{BTreeMap, BinaryHeap, HashMap, HashSet, LinkedList, VecDeque},
     hash::Hash,
     str::FromStr,
@@ -12,25 +16,146 @@ use std::{
 pub struct FormContext<'a> {
     /// The name of the field being parsed, if any.
     pub field_name: Option<&'a str>,
+
+    /// The path of the parent field when parsing a nested form, if any.
+    pub prefix: Option<&'a str>,
+
+    /// The notation used to join the names of nested fields.
+    pub notation: Notation,
+}
+
+impl<'a> FormContext<'a> {
+    /// Returns the full name of the given field, joined with the `prefix` if any.
+    pub fn field_path<'b>(&self, name: &'b str) -> Cow<'b, str> {
+        match self.prefix {
+            Some(prefix) => Cow::Owned(self.notation.join(prefix, name)),
+            None => Cow::Borrowed(name),
+        }
+    }
+
+    /// Returns the name of the field being parsed without the `prefix` of its parent.
+    pub fn local_name(&self) -> Option<&'a str> {
+        let field_name = self.field_name?;
+        let Some(rest) = self
+            .prefix
+            .and_then(|prefix| field_name.strip_prefix(prefix))
+        else {
+            return Some(field_name);
+        };
+
+        let local_name = match self.notation {
+            Notation::Brackets => rest.strip_prefix('[').and_then(|s| s.strip_suffix(']')),
+            Notation::Dots => rest.strip_prefix('.'),
+        };
+
+        match local_name {
+            Some(name) if !name.is_empty() => Some(name),
+            _ => Some(field_name),
+        }
+    }
+
+    /// Sets the path of the field errors that only have a name, joining the name with the `prefix`.
+    ///
+    /// Used to attach the errors of a validation to the fields of the value being parsed.
+    pub fn scope(&self, error: Error) -> Error {
+        let scope = |error: FieldError| match (error.path(), error.name()) {
+            (None, Some(name)) => {
+                let path = self.field_path(name).into_owned();
+                error.with_path(path)
+            }
+            _ => error,
+        };
+
+        match error {
+            Error::Field(error) => Error::Field(scope(error)),
+            Error::Multiple(errors) => {
+                let mut scoped = Errors::new();
+                for error in errors {
+                    scoped.push(scope(error));
+                }
+
+                Error::Multiple(scoped)
+            }
+            error => error,
+        }
+    }
+
+    /// Returns a [`FieldError`] of the given kind for the field being parsed.
+    pub fn field_error(&self, kind: FieldErrorKind) -> FieldError {
+        let mut error = FieldError::new(kind);
+
+        if let Some(path) = self.field_name {
+            error = error.with_path(path);
+        }
+
+        if let Some(name) = self.local_name() {
+            error = error.with_name(name);
+        }
+
+        error
+    }
+}
+
+/// The notation used for the names of nested fields.
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Notation {
+    /// Nested fields use brackets: `address[street]`.
+    #[default]
+    Brackets,
+
+    /// Nested fields use dots: `address.street`.
+    Dots,
+}
+
+impl Notation {
+    /// Joins the name of a field with the path of its parent.
+    pub fn join(&self, parent: &str, name: &str) -> String {
+        match self {
+            Notation::Brackets => format!("{parent}[{name}]"),
+            Notation::Dots => format!("{parent}.{name}"),
+        }
+    }
+
+    /// Returns `true` if the field `name` is nested in the given `parent` path.
+    pub fn is_nested(&self, parent: &str, name: &str) -> bool {
+        let Some(rest) = name.strip_prefix(parent) else {
+            return false;
+        };
+
+        match self {
+            Notation::Brackets => rest.starts_with('['),
+            Notation::Dots => rest.starts_with('.'),
+        }
+    }
 }
 
 /// Allows to create a type from a [`multer::Multipart`].
 pub trait FromMultipart: Sized {
     /// Constructs this type from the given multipart form.
     fn from_multipart(multipart: &MultipartForm, ctx: FormContext<'_>) -> Result<Self, Error>;
+
+    /// Returns the limits of the fields of this type, used by [`MultipartForm::parse`].
+    fn constraints() -> FormConstraints {
+        FormConstraints::new()
+    }
 }
 
 impl<T: FromMultipartField> FromMultipart for T {
     fn from_multipart(multipart: &MultipartForm, ctx: FormContext<'_>) -> Result<Self, Error> {
         let Some(field_name) = ctx.field_name else {
-            return Err(Error::new("FormContext does not specified a field to parse"));
+            return Err(Error::new(
+                "FormContext does not specified a field to parse",
+            ));
         };
 
         let field = multipart
             .get_by_name(field_name)
-            .ok_or_else(|| Error::new(format!("`{field_name}` form field was not found")))?;
+            .ok_or_else(|| ctx.field_error(FieldErrorKind::Missing))?;
 
-        T::from_field(field)
+        T::from_field(field).map_err(|err| {
+            let name = ctx.local_name().unwrap_or(field_name);
+            err.for_field(name, field_name, Some(field.index()))
+        })
     }
 }
 
@@ -48,8 +173,17 @@ where
                 continue;
             };
 
-            let key = K::from_str(name).map_err(Error::new)?;
-            let value = V::from_field(field)?;
+            let key = K::from_str(name).map_err(|err| {
+                FieldError::new(FieldErrorKind::Parse)
+                    .with_name(name)
+                    .with_path(name)
+                    .with_index(field.index())
+                    .with_source(err)
+            })?;
+
+            let value = V::from_field(field)
+                .map_err(|err| err.for_field(name, name, Some(field.index())))?;
+
             map.insert(key, value);
         }
 
@@ -71,8 +205,17 @@ where
                 continue;
             };
 
-            let key = K::from_str(name).map_err(Error::new)?;
-            let value = V::from_field(field)?;
+            let key = K::from_str(name).map_err(|err| {
+                FieldError::new(FieldErrorKind::Parse)
+                    .with_name(name)
+                    .with_path(name)
+                    .with_index(field.index())
+                    .with_source(err)
+            })?;
+
+            let value = V::from_field(field)
+                .map_err(|err| err.for_field(name, name, Some(field.index())))?;
+
             map.insert(key, value);
         }
 
@@ -82,12 +225,8 @@ where
 
 impl<T: FromMultipartField> FromMultipart for Vec<T> {
     fn from_multipart(multipart: &MultipartForm, ctx: FormContext<'_>) -> Result<Self, Error> {
-        let name = ctx.field_name;
-
         Ok(multipart
-            .fields()
-            .iter()
-            .filter(|f| name.is_none() || name == f.name())
+            .fields_named(ctx.field_name)
             .filter_map(|f| T::from_field(f).ok())
             .collect())
     }
@@ -95,12 +234,8 @@ impl<T: FromMultipartField> FromMultipart for Vec<T> {
 
 impl<T: FromMultipartField> FromMultipart for VecDeque<T> {
     fn from_multipart(multipart: &MultipartForm, ctx: FormContext<'_>) -> Result<Self, Error> {
-        let name = ctx.field_name;
-
         Ok(multipart
-            .fields()
-            .iter()
-            .filter(|f| name.is_none() || name == f.name())
+            .fields_named(ctx.field_name)
             .filter_map(|f| T::from_field(f).ok())
             .collect())
     }
@@ -108,12 +243,8 @@ impl<T: FromMultipartField> FromMultipart for VecDeque<T> {
 
 impl<T: FromMultipartField> FromMultipart for LinkedList<T> {
     fn from_multipart(multipart: &MultipartForm, ctx: FormContext<'_>) -> Result<Self, Error> {
-        let name = ctx.field_name;
-
         Ok(multipart
-            .fields()
-            .iter()
-            .filter(|f| name.is_none() || name == f.name())
+            .fields_named(ctx.field_name)
             .filter_map(|f| T::from_field(f).ok())
             .collect())
     }
@@ -121,12 +252,8 @@ impl<T: FromMultipartField> FromMultipart for LinkedList<T> {
 
 impl<T: FromMultipartField + Hash + Eq> FromMultipart for HashSet<T> {
     fn from_multipart(multipart: &MultipartForm, ctx: FormContext<'_>) -> Result<Self, Error> {
-        let name = ctx.field_name;
-
         Ok(multipart
-            .fields()
-            .iter()
-            .filter(|f| name.is_none() || name == f.name())
+            .fields_named(ctx.field_name)
             .filter_map(|f| T::from_field(f).ok())
             .collect())
     }
@@ -134,12 +261,8 @@ impl<T: FromMultipartField + Hash + Eq> FromMultipart for HashSet<T> {
 
 impl<T: FromMultipartField + Ord> FromMultipart for BinaryHeap<T> {
     fn from_multipart(multipart: &MultipartForm, ctx: FormContext<'_>) -> Result<Self, Error> {
-        let name = ctx.field_name;
-
         Ok(multipart
-            .fields()
-            .iter()
-            .filter(|f| name.is_none() || name == f.name())
+            .fields_named(ctx.field_name)
             .filter_map(|f| T::from_field(f).ok())
             .collect())
     }
@@ -170,6 +293,7 @@ mod tests {
                 multipart,
                 FormContext {
                     field_name: Some("name"),
+                    ..Default::default()
                 },
             )?;
 
@@ -177,6 +301,7 @@ mod tests {
                 multipart,
                 FormContext {
                     field_name: Some("email"),
+                    ..Default::default()
                 },
             )?;
 
@@ -184,6 +309,7 @@ mod tests {
                 multipart,
                 FormContext {
                     field_name: Some("age"),
+                    ..Default::default()
                 },
             )?;
 
@@ -191,6 +317,7 @@ mod tests {
                 multipart,
                 FormContext {
                     field_name: Some("married"),
+                    ..Default::default()
                 },
             )?;
 
@@ -198,6 +325,7 @@ mod tests {
                 multipart,
                 FormContext {
                     field_name: Some("photo"),
+                    ..Default::default()
                 },
             )?;
 
@@ -224,7 +352,7 @@ mod tests {
         assert_eq!(person.name, "John Smith");
         assert_eq!(person.email, "john@example.com");
         assert_eq!(person.age, 25);
-        assert_eq!(person.married, true);
+        assert!(person.married);
 
         let str = String::from_utf8(person.photo.bytes().to_vec()).unwrap();
         assert_eq!(str, "[Binary data]");
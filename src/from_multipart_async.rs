@@ -0,0 +1,32 @@
+use crate::{
+    error::Error, from_multipart_field::FromMultipartField, multipart_form::MultipartField,
+};
+use multer::{Field, Multipart};
+use std::future::Future;
+
+/// Allows to create a type by consuming the fields of a [`multer::Multipart`] as they arrive,
+/// without buffering the whole form first.
+///
+/// This trait can be implemented using `#[derive(FromMultipartAsync)]`.
+pub trait FromMultipartAsync: Sized {
+    /// Reads the fields of the multipart and constructs this type.
+    fn from_multipart_async(
+        multipart: Multipart<'_>,
+    ) -> impl Future<Output = Result<Self, Error>> + Send;
+}
+
+/// Allows to create a value from a [`multer::Field`] while is being received.
+///
+/// Any [`FromMultipartField`] reads the whole field into memory, implement this trait
+/// to handle the chunks of the field instead, like piping a file to disk or an uploader.
+pub trait FromFieldAsync: Sized {
+    /// Reads the given field and constructs the value.
+    fn from_field_async(field: Field<'_>) -> impl Future<Output = Result<Self, Error>> + Send;
+}
+
+impl<T: FromMultipartField> FromFieldAsync for T {
+    async fn from_field_async(field: Field<'_>) -> Result<Self, Error> {
+        let field = MultipartField::read(field).await?;
+        T::from_field(&field)
+    }
+}
@@ -65,7 +65,7 @@ macro_rules! from_field_impls {
         $(
             impl FromMultipartField for $t {
                 fn from_field(field: &MultipartField) -> Result<Self, Error> {
-                    let text = field.text();
+                    let text = field.try_text().map_err(Error::new)?;
                     text.parse().map_err(Error::new)
                 }
             }
@@ -110,7 +110,7 @@ mod time {
         fn from_field(field: &MultipartField) -> Result<Self, Error> {
             let format =
                 time::macros::format_description!("[hour]:[minute]:[second].[subsecond digits:9]");
-            let text = field.text();
+            let text = field.try_text().map_err(Error::new)?;
             let time = time::Time::parse(&text, format).map_err(Error::new)?;
             Ok(time)
         }
@@ -119,7 +119,7 @@ mod time {
     impl FromMultipartField for time::Date {
         fn from_field(field: &MultipartField) -> Result<Self, Error> {
             let format = time::macros::format_description!("[year]-[month]-[day]");
-            let text = field.text();
+            let text = field.try_text().map_err(Error::new)?;
             let date = time::Date::parse(&text, format).map_err(Error::new)?;
             Ok(date)
         }
@@ -130,7 +130,7 @@ mod time {
             let format = time::macros::format_description!(
                 "[year]-[month]-[day] [hour]:[minute]:[second].[subsecond digits:9]"
             );
-            let text = field.text();
+            let text = field.try_text().map_err(Error::new)?;
             let date = time::PrimitiveDateTime::parse(&text, format).map_err(Error::new)?;
             Ok(date)
         }
@@ -145,7 +145,7 @@ mod uuid {
 
     impl FromMultipartField for Uuid {
         fn from_field(field: &MultipartField) -> Result<Self, Error> {
-            let text = field.text();
+            let text = field.try_text().map_err(Error::new)?;
             text.parse().map_err(Error::new)
         }
     }
@@ -158,7 +158,7 @@ mod json {
 
     impl FromMultipartField for serde_json::Value {
         fn from_field(field: &MultipartField) -> Result<Self, Error> {
-            let text = field.text();
+            let text = field.try_text().map_err(Error::new)?;
             serde_json::to_value(&text).map_err(Error::new)
         }
     }
@@ -187,7 +187,7 @@ mod misc {
         }
     }
 
-    impl<'a, T> FromMultipartField for &'a PhantomData<T> {
+    impl<T> FromMultipartField for &PhantomData<T> {
         fn from_field(_: &MultipartField) -> Result<Self, Error> {
             Ok(&PhantomData)
         }
@@ -251,7 +251,7 @@ mod atomics {
             $(
                 impl FromMultipartField for $atomic {
                     fn from_field(field: &MultipartField) -> Result<Self, Error> {
-                        let text = field.text();
+                        let text = field.try_text().map_err(Error::new)?;
                         let value = text.parse().map_err(Error::new)?;
                         Ok($atomic::new(value))
                     }
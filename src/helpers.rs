@@ -36,7 +36,7 @@ impl InputValue {
 }
 
 /// A builder for multipart form data strings.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct MultipartFormBuilder {
     fields: IndexMap<String, InputValue>,
 }
@@ -369,12 +369,10 @@ mod tests {
         builder.text("username", "john_doe");
         let result = builder.build("my_boundary");
 
-        let expected = format!(
-            "--my_boundary\r\n\
-             Content-Disposition: form-data; name=\"username\"\r\n\r\n\
-             john_doe\r\n\
-             --my_boundary--\r\n"
-        );
+        let expected = "--my_boundary\r\n\
+         Content-Disposition: form-data; name=\"username\"\r\n\r\n\
+         john_doe\r\n\
+         --my_boundary--\r\n";
 
         assert_eq!(result, expected);
     }
@@ -384,22 +382,20 @@ mod tests {
         let mut builder = MultipartFormBuilder::new();
         builder.text("username", "john_doe").raw_file(
             "avatar",
-            &[0x01, 0x02, 0x03],
+            [0x01, 0x02, 0x03],
             "avatar.png",
             mime::IMAGE_PNG,
         );
         let result = builder.build("my_boundary");
 
-        let expected = format!(
-            "--my_boundary\r\n\
-             Content-Disposition: form-data; name=\"username\"\r\n\r\n\
-             john_doe\r\n\
-             --my_boundary\r\n\
-             Content-Disposition: form-data; name=\"avatar\"; filename=\"avatar.png\"\r\n\
-             Content-Type: image/png\r\n\r\n\
-             \u{01}\u{02}\u{03}\r\n\
-             --my_boundary--\r\n"
-        );
+        let expected = "--my_boundary\r\n\
+         Content-Disposition: form-data; name=\"username\"\r\n\r\n\
+         john_doe\r\n\
+         --my_boundary\r\n\
+         Content-Disposition: form-data; name=\"avatar\"; filename=\"avatar.png\"\r\n\
+         Content-Type: image/png\r\n\r\n\
+         \u{01}\u{02}\u{03}\r\n\
+         --my_boundary--\r\n";
 
         assert_eq!(result, expected);
     }
@@ -409,29 +405,27 @@ mod tests {
         let mut builder = MultipartFormBuilder::new();
         builder
             .text("username", "john_doe")
-            .raw_file("avatar", &[0x01, 0x02, 0x03], "avatar.png", mime::IMAGE_PNG)
+            .raw_file("avatar", [0x01, 0x02, 0x03], "avatar.png", mime::IMAGE_PNG)
             .raw_file(
                 "background",
-                &[0x04, 0x05, 0x06],
+                [0x04, 0x05, 0x06],
                 "background.png",
                 mime::IMAGE_PNG,
             );
         let result = builder.build("my_boundary");
 
-        let expected = format!(
-            "--my_boundary\r\n\
-             Content-Disposition: form-data; name=\"username\"\r\n\r\n\
-             john_doe\r\n\
-             --my_boundary\r\n\
-             Content-Disposition: form-data; name=\"avatar\"; filename=\"avatar.png\"\r\n\
-             Content-Type: image/png\r\n\r\n\
-             \u{01}\u{02}\u{03}\r\n\
-             --my_boundary\r\n\
-             Content-Disposition: form-data; name=\"background\"; filename=\"background.png\"\r\n\
-             Content-Type: image/png\r\n\r\n\
-             \u{04}\u{05}\u{06}\r\n\
-             --my_boundary--\r\n"
-        );
+        let expected = "--my_boundary\r\n\
+         Content-Disposition: form-data; name=\"username\"\r\n\r\n\
+         john_doe\r\n\
+         --my_boundary\r\n\
+         Content-Disposition: form-data; name=\"avatar\"; filename=\"avatar.png\"\r\n\
+         Content-Type: image/png\r\n\r\n\
+         \u{01}\u{02}\u{03}\r\n\
+         --my_boundary\r\n\
+         Content-Disposition: form-data; name=\"background\"; filename=\"background.png\"\r\n\
+         Content-Type: image/png\r\n\r\n\
+         \u{04}\u{05}\u{06}\r\n\
+         --my_boundary--\r\n";
 
         assert_eq!(result, expected);
     }
@@ -439,16 +433,14 @@ mod tests {
     #[test]
     fn test_build_only_file() {
         let mut builder = MultipartFormBuilder::new();
-        builder.raw_file("avatar", &[0x01, 0x02, 0x03], "avatar.png", mime::IMAGE_PNG);
+        builder.raw_file("avatar", [0x01, 0x02, 0x03], "avatar.png", mime::IMAGE_PNG);
         let result = builder.build("my_boundary");
 
-        let expected = format!(
-            "--my_boundary\r\n\
-             Content-Disposition: form-data; name=\"avatar\"; filename=\"avatar.png\"\r\n\
-             Content-Type: image/png\r\n\r\n\
-             \u{01}\u{02}\u{03}\r\n\
-             --my_boundary--\r\n"
-        );
+        let expected = "--my_boundary\r\n\
+         Content-Disposition: form-data; name=\"avatar\"; filename=\"avatar.png\"\r\n\
+         Content-Type: image/png\r\n\r\n\
+         \u{01}\u{02}\u{03}\r\n\
+         --my_boundary--\r\n";
 
         assert_eq!(result, expected);
     }
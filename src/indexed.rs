@@ -0,0 +1,173 @@
+use crate::{
+    constraints::FormConstraints,
+    error::{Error, FieldError, FieldErrorKind},
+    from_multipart::FormContext,
+    FromMultipart, MultipartForm, Notation,
+};
+
+/// Provides a way to collect a list of values from indexed fields like `items[0][name]`.
+///
+/// Each index is parsed using the fields under it, the indices must start at `0`,
+/// be sent in order and must not have gaps or leading zeros.
+pub struct Indexed<T>(Vec<T>);
+
+impl<T> Indexed<T> {
+    /// Returns all the collected values.
+    pub fn into_inner(self) -> Vec<T> {
+        self.0
+    }
+}
+
+impl<T: FromMultipart> FromMultipart for Indexed<T> {
+    fn from_multipart(multipart: &MultipartForm, ctx: FormContext<'_>) -> Result<Self, Error> {
+        let Some(list_name) = ctx.prefix.or(ctx.field_name) else {
+            return Err(Error::new(
+                "FormContext does not specified a field to parse",
+            ));
+        };
+
+        // The first field of each index, in the order they were received
+        let mut fields = Vec::new();
+
+        for name in multipart.nested_names(list_name, ctx.notation) {
+            let (Some(index), Some(field)) = (
+                parse_index(ctx.notation, list_name, name),
+                multipart.get_by_name(name),
+            ) else {
+                continue;
+            };
+
+            match index {
+                Ok(index) => fields.push((field.index(), index)),
+                Err(index) => {
+                    return Err(ctx
+                        .field_error(FieldErrorKind::Parse)
+                        .with_path(list_name)
+                        .with_index(field.index())
+                        .with_source(format!(
+                            "invalid index `{index}`, indices must not have leading zeros"
+                        ))
+                        .into());
+                }
+            }
+        }
+
+        fields.sort_unstable();
+
+        let mut indices: Vec<usize> = vec![];
+
+        for (field_index, index) in fields {
+            match indices.last() {
+                Some(last) if *last == index => {}
+                Some(_) if indices.binary_search(&index).is_ok() => {}
+                Some(last) if index < *last => {
+                    return Err(ctx
+                        .field_error(FieldErrorKind::Parse)
+                        .with_path(list_name)
+                        .with_index(field_index)
+                        .with_source(format!(
+                            "indices are out of order, found `{index}` after `{last}`"
+                        ))
+                        .into());
+                }
+                _ => indices.push(index),
+            }
+        }
+
+        let mut values = Vec::with_capacity(indices.len());
+
+        for (expected, index) in indices.into_iter().enumerate() {
+            if index != expected {
+                return Err(FieldError::new(FieldErrorKind::Missing)
+                    .with_name(expected.to_string())
+                    .with_path(ctx.notation.join(list_name, &expected.to_string()))
+                    .into());
+            }
+
+            let path = ctx.notation.join(list_name, &index.to_string());
+            let value = T::from_multipart(
+                multipart,
+                FormContext {
+                    field_name: Some(&path),
+                    prefix: Some(&path),
+                    notation: ctx.notation,
+                },
+            )?;
+
+            values.push(value);
+        }
+
+        Ok(Indexed(values))
+    }
+
+    fn constraints() -> FormConstraints {
+        // The limits of the items apply to any index
+        T::constraints().indexed(Notation::Brackets)
+    }
+}
+
+/// Returns the index of a field like `items[0]` or `items[0][name]` in the given list,
+/// or the index as is if has leading zeros, like `items[01]`.
+fn parse_index<'a>(
+    notation: Notation,
+    list_name: &str,
+    name: &'a str,
+) -> Option<Result<usize, &'a str>> {
+    let rest = name.strip_prefix(list_name)?;
+
+    let index = match notation {
+        Notation::Brackets => {
+            let rest = rest.strip_prefix('[')?;
+            &rest[..rest.find(']')?]
+        }
+        Notation::Dots => {
+            let rest = rest.strip_prefix('.')?;
+            let end = rest.find(['.', '[']).unwrap_or(rest.len());
+            &rest[..end]
+        }
+    };
+
+    if index.is_empty() || !index.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    match index.parse() {
+        Ok(value) if index.len() == 1 || !index.starts_with('0') => Some(Ok(value)),
+        _ => Some(Err(index)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_index;
+    use crate::Notation;
+
+    #[test]
+    fn parse_index_test() {
+        assert_eq!(
+            parse_index(Notation::Brackets, "items", "items[0]"),
+            Some(Ok(0))
+        );
+        assert_eq!(
+            parse_index(Notation::Brackets, "items", "items[12][name]"),
+            Some(Ok(12))
+        );
+        assert_eq!(parse_index(Notation::Brackets, "items", "items[]"), None);
+        assert_eq!(
+            parse_index(Notation::Brackets, "items", "items[name]"),
+            None
+        );
+        assert_eq!(parse_index(Notation::Brackets, "items", "other[0]"), None);
+        assert_eq!(
+            parse_index(Notation::Dots, "items", "items.3.name"),
+            Some(Ok(3))
+        );
+        assert_eq!(parse_index(Notation::Dots, "items", "items.3"), Some(Ok(3)));
+        assert_eq!(parse_index(Notation::Dots, "items", "items[3]"), None);
+        assert_eq!(
+            parse_index(Notation::Brackets, "items", "items[00][name]"),
+            Some(Err("00"))
+        );
+        assert_eq!(parse_index(Notation::Brackets, "items", "items[+1]"), None);
+    }
+}
@@ -1,7 +1,12 @@
 #![doc = include_str!("../README.md")]
 
+mod blocking;
+
+mod constraints;
+pub use constraints::FormConstraints;
+
 mod error;
-pub use error::Error;
+pub use error::{ConstraintValue, Error, Errors, FieldError, FieldErrorKind};
 
 mod file_collection;
 pub use file_collection::FileCollection;
@@ -10,7 +15,13 @@ mod form_file;
 pub use form_file::FormFile;
 
 mod from_multipart;
-pub use from_multipart::{FormContext, FromMultipart};
+pub use from_multipart::{FormContext, FromMultipart, Notation};
+
+mod from_multipart_async;
+pub use from_multipart_async::{FromFieldAsync, FromMultipartAsync};
+
+mod indexed;
+pub use indexed::Indexed;
 
 mod from_multipart_field;
 pub use from_multipart_field::FromMultipartField;
@@ -18,8 +29,23 @@ pub use from_multipart_field::FromMultipartField;
 mod multipart_form;
 pub use multipart_form::{MultipartField, MultipartForm};
 
+mod router;
+pub use router::{FieldRouter, FieldStream, RoutedFields};
+
+mod save;
+pub use save::SaveOptions;
+
+mod storage;
+pub use storage::Storage;
+
+pub mod validate;
+
+mod response;
+#[cfg(feature = "problem")]
+pub use response::PROBLEM_JSON;
+
 // Macro
-pub use multer_derive_macros::FromMultipart;
+pub use multer_derive_macros::{FromMultipart, FromMultipartAsync, FromMultipartField};
 
 // Re-exports
 pub use http::header;
@@ -1,8 +1,21 @@
+use crate::{
+    constraints::FormConstraints,
+    error::Error,
+    storage::{FieldData, Storage},
+    FromMultipart, Notation,
+};
 use encoding_rs::Encoding;
+use futures_core::Stream;
 use http::HeaderMap;
 use mime::Mime;
-use multer::{bytes::Bytes, Multipart};
-use std::{borrow::Cow, ops::Index};
+use multer::{bytes::Bytes, Field, Multipart};
+use std::{
+    borrow::Cow,
+    collections::{BTreeMap, HashMap},
+    io::Read,
+    ops::{Bound, Index, Range},
+    path::Path,
+};
 
 /// A field in a multipart form.
 #[derive(Clone)]
@@ -11,11 +24,30 @@ pub struct MultipartField {
     file_name: Option<String>,
     content_type: Option<Mime>,
     headers: HeaderMap,
-    bytes: Bytes,
+    data: FieldData,
     index: usize,
 }
 
 impl MultipartField {
+    /// Reads the whole [`multer::Field`] into memory.
+    pub(crate) async fn read(field: Field<'_>) -> multer::Result<MultipartField> {
+        let name = field.name().map(|s| s.to_owned());
+        let file_name = field.file_name().map(|s| s.to_owned());
+        let content_type = field.content_type().cloned();
+        let headers = field.headers().clone();
+        let index = field.index();
+        let bytes = field.bytes().await?;
+
+        Ok(MultipartField {
+            name,
+            file_name,
+            content_type,
+            headers,
+            data: FieldData::Memory(bytes),
+            index,
+        })
+    }
+
     /// Returns the index of this field in the form.
     pub fn index(&self) -> usize {
         self.index
@@ -42,19 +74,68 @@ impl MultipartField {
     }
 
     /// Returns the bytes content of this field.
+    ///
+    /// If the field is stored in a temporary file, the file is read into memory the first time.
+    ///
+    /// # Panics
+    /// If the temporary file cannot be read, use [`MultipartField::try_bytes`] to handle the errors.
     pub fn bytes(&self) -> &Bytes {
-        &self.bytes
+        self.try_bytes().expect("failed to read temporary file")
+    }
+
+    /// Returns the bytes content of this field,
+    /// or an error if the field is stored in a temporary file which cannot be read.
+    pub fn try_bytes(&self) -> std::io::Result<&Bytes> {
+        self.data.try_bytes()
+    }
+
+    /// Returns the size in bytes of this field.
+    pub fn size(&self) -> u64 {
+        self.data.len()
+    }
+
+    /// Returns the path of the temporary file of this field, if was stored in a file.
+    pub fn path(&self) -> Option<&Path> {
+        self.data.path()
+    }
+
+    /// Returns a reader over the contents of this field.
+    pub fn reader(&self) -> std::io::Result<impl Read + '_> {
+        self.data.reader()
+    }
+
+    pub(crate) fn data(&self) -> &FieldData {
+        &self.data
     }
 
     /// Converts the bytes of this field to a `utf-8` string.
+    ///
+    /// # Panics
+    /// If the temporary file cannot be read, use [`MultipartField::try_text`] to handle the errors.
     pub fn text(&self) -> String {
         self.text_with_charset("utf-8")
     }
 
+    /// Converts the bytes of this field to a `utf-8` string,
+    /// or an error if the field is stored in a temporary file which cannot be read.
+    pub fn try_text(&self) -> std::io::Result<String> {
+        self.try_text_with_charset("utf-8")
+    }
+
     /// Converts this field to a string using the given encoding.
     ///
     /// Checkout: <https://docs.rs/encoding_rs/latest/encoding_rs/struct.Encoding.html>
+    ///
+    /// # Panics
+    /// If the temporary file cannot be read, use [`MultipartField::try_text_with_charset`] to handle the errors.
     pub fn text_with_charset(&self, default_encoding: &str) -> String {
+        self.try_text_with_charset(default_encoding)
+            .expect("failed to read temporary file")
+    }
+
+    /// Converts this field to a string using the given encoding,
+    /// or an error if the field is stored in a temporary file which cannot be read.
+    pub fn try_text_with_charset(&self, default_encoding: &str) -> std::io::Result<String> {
         let encoding_name = self
             .content_type()
             .and_then(|mime| mime.get_param(mime::CHARSET))
@@ -62,12 +143,12 @@ impl MultipartField {
             .unwrap_or(default_encoding);
 
         let encoding = Encoding::for_label(encoding_name.as_bytes()).unwrap_or(encoding_rs::UTF_8);
-        let bytes = self.bytes();
+        let bytes = self.try_bytes()?;
         let (text, ..) = encoding.decode(bytes);
 
         match text {
-            Cow::Owned(s) => s,
-            Cow::Borrowed(s) => String::from(s),
+            Cow::Owned(s) => Ok(s),
+            Cow::Borrowed(s) => Ok(String::from(s)),
         }
     }
 }
@@ -76,31 +157,169 @@ impl MultipartField {
 #[derive(Clone)]
 pub struct MultipartForm {
     fields: Vec<MultipartField>,
+    // The positions in `fields` of the fields with a name, the fields with the same name are together.
+    // The fields are stored once in the order they were received, so the groups are not slices.
+    grouped: Vec<usize>,
+    // The range in `grouped` of the fields with each name, sorted to find the nested fields of a path
+    index: BTreeMap<String, Range<usize>>,
+    // The names of the fields in the order they were first received
+    names: Vec<String>,
 }
 
 impl MultipartForm {
+    /// Constructs a form from the received fields, grouping the fields by name.
+    fn new(fields: Vec<MultipartField>) -> Self {
+        let mut positions = HashMap::<&str, Vec<usize>>::new();
+        let mut names = Vec::new();
+
+        for (pos, field) in fields.iter().enumerate() {
+            let Some(name) = field.name() else {
+                continue;
+            };
+
+            positions
+                .entry(name)
+                .or_insert_with(|| {
+                    names.push(name.to_owned());
+                    Vec::new()
+                })
+                .push(pos);
+        }
+
+        let mut grouped = Vec::new();
+        let mut index = BTreeMap::new();
+
+        for name in &names {
+            let start = grouped.len();
+            grouped.extend_from_slice(&positions[name.as_str()]);
+            index.insert(name.clone(), start..grouped.len());
+        }
+
+        MultipartForm {
+            fields,
+            grouped,
+            index,
+            names,
+        }
+    }
+
     /// Creates a multipart form by caching all the fields in the [`multer::Multipart`].
     pub async fn with_multipart(mut multipart: Multipart<'_>) -> multer::Result<MultipartForm> {
         let mut fields = vec![];
 
-        while let Some((index, field)) = multipart.next_field_with_idx().await? {
+        while let Some(field) = multipart.next_field().await? {
+            fields.push(MultipartField::read(field).await?);
+        }
+
+        Ok(MultipartForm::new(fields))
+    }
+
+    /// Creates a multipart form by caching all the fields in the [`multer::Multipart`],
+    /// returning an error as soon as a field exceeds the given constraints.
+    pub async fn with_constraints(
+        multipart: Multipart<'_>,
+        constraints: &FormConstraints,
+    ) -> Result<MultipartForm, Error> {
+        MultipartForm::with_storage(multipart, constraints, &Storage::memory()).await
+    }
+
+    /// Creates a multipart form by caching all the fields in the [`multer::Multipart`] using the given storage,
+    /// returning an error as soon as a field exceeds the given constraints.
+    pub async fn with_storage(
+        mut multipart: Multipart<'_>,
+        constraints: &FormConstraints,
+        storage: &Storage,
+    ) -> Result<MultipartForm, Error> {
+        let mut fields = vec![];
+        let mut files = 0;
+        let mut total_size = 0;
+
+        while let Some((index, mut field)) = multipart.next_field_with_idx().await? {
             let name = field.name().map(|s| s.to_owned());
             let file_name = field.file_name().map(|s| s.to_owned());
             let content_type = field.content_type().cloned();
             let headers = field.headers().clone();
-            let bytes = field.bytes().await?;
+            let is_file = file_name.is_some();
+
+            constraints.check_field(name.as_deref(), is_file, fields.len(), files)?;
+
+            let max_size = constraints.max_size(name.as_deref(), is_file);
+            let mut buffer = storage.buffer(is_file);
+
+            while let Some(chunk) = field.chunk().await? {
+                total_size += chunk.len() as u64;
+                buffer.write(chunk).await.map_err(Error::new)?;
+                constraints.check_size(name.as_deref(), max_size, buffer.len(), total_size)?;
+            }
+
+            if is_file {
+                files += 1;
+            }
 
             fields.push(MultipartField {
                 name,
                 file_name,
                 content_type,
                 headers,
-                bytes,
+                data: buffer.finish().await.map_err(Error::new)?,
                 index,
             })
         }
 
-        Ok(MultipartForm { fields })
+        Ok(MultipartForm::new(fields))
+    }
+
+    /// Creates a multipart form by caching all the fields in the body of a `multipart/form-data` request.
+    ///
+    /// Returns [`multer::Error::NoMultipart`] if the request has other content type
+    /// and [`multer::Error::NoBoundary`] if the content type does not have a boundary.
+    pub async fn from_request<B, O, E>(request: http::Request<B>) -> multer::Result<MultipartForm>
+    where
+        B: Stream<Item = Result<O, E>> + Send,
+        O: Into<Bytes> + 'static,
+        E: Into<Box<dyn std::error::Error + Send + Sync>>,
+    {
+        let (parts, body) = request.into_parts();
+        MultipartForm::from_body(&parts.headers, body).await
+    }
+
+    /// Creates a multipart form by caching all the fields in the body of a `multipart/form-data` request
+    /// with the given headers, see [`MultipartForm::from_request`].
+    pub async fn from_body<B, O, E>(headers: &HeaderMap, body: B) -> multer::Result<MultipartForm>
+    where
+        B: Stream<Item = Result<O, E>> + Send,
+        O: Into<Bytes> + 'static,
+        E: Into<Box<dyn std::error::Error + Send + Sync>>,
+    {
+        let multipart = MultipartForm::multipart_from_body(headers, body)?;
+        MultipartForm::with_multipart(multipart).await
+    }
+
+    /// Returns the [`multer::Multipart`] of the body of a `multipart/form-data` request with the given headers,
+    /// used to read the form with constraints or as a stream.
+    pub fn multipart_from_body<'r, B, O, E>(
+        headers: &HeaderMap,
+        body: B,
+    ) -> multer::Result<Multipart<'r>>
+    where
+        B: Stream<Item = Result<O, E>> + Send + 'r,
+        O: Into<Bytes> + 'static,
+        E: Into<Box<dyn std::error::Error + Send + Sync>> + 'r,
+    {
+        let content_type = headers
+            .get(http::header::CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .ok_or(multer::Error::NoMultipart)?;
+
+        let boundary = multer::parse_boundary(content_type)?;
+        Ok(Multipart::new(body, boundary))
+    }
+
+    /// Parses a value from the [`multer::Multipart`], applying the [`FromMultipart::constraints`]
+    /// of the type while the fields are read.
+    pub async fn parse<T: FromMultipart>(multipart: Multipart<'_>) -> Result<T, Error> {
+        let form = MultipartForm::with_constraints(multipart, &T::constraints()).await?;
+        T::from_multipart(&form, Default::default())
     }
 
     /// Returns the field in the given index.
@@ -108,9 +327,93 @@ impl MultipartForm {
         self.fields.get(index)
     }
 
-    /// Returns the field with the given name
+    /// Returns the first field with the given name
     pub fn get_by_name(&self, name: &str) -> Option<&MultipartField> {
-        self.fields.iter().find(|x| x.name() == Some(name))
+        self.get_all(name).next()
+    }
+
+    /// Returns all the fields with the given name, in the order they were received.
+    pub fn get_all(
+        &self,
+        name: &str,
+    ) -> impl ExactSizeIterator<Item = &MultipartField> + Clone + '_ {
+        self.group(name)
+    }
+
+    /// Returns the names of the fields without repeating, in the order they were first received.
+    pub fn names(&self) -> impl ExactSizeIterator<Item = &str> {
+        self.names.iter().map(String::as_str)
+    }
+
+    /// Returns `true` if the form has a field with the given name.
+    pub fn contains(&self, name: &str) -> bool {
+        self.index.contains_key(name)
+    }
+
+    /// Returns the number of fields with the given name.
+    pub fn count(&self, name: &str) -> usize {
+        self.group(name).len()
+    }
+
+    /// Returns the fields grouped by name, in the order each name was first received.
+    ///
+    /// The fields of each group are in the order they were received,
+    /// the fields are not copied so each group is an iterator instead of a slice.
+    pub fn groups(
+        &self,
+    ) -> impl ExactSizeIterator<
+        Item = (
+            &str,
+            impl ExactSizeIterator<Item = &MultipartField> + Clone + '_,
+        ),
+    > {
+        self.names
+            .iter()
+            .map(|name| (name.as_str(), self.group(name)))
+    }
+
+    /// Returns the names of the fields nested in the given path, like `address[street]` in `address`,
+    /// sorted by name.
+    pub fn nested_names<'a>(
+        &'a self,
+        parent: &str,
+        notation: Notation,
+    ) -> impl Iterator<Item = &'a str> + 'a {
+        let prefix = match notation {
+            Notation::Brackets => format!("{parent}["),
+            Notation::Dots => format!("{parent}."),
+        };
+
+        self.index
+            .range::<str, _>((Bound::Included(prefix.as_str()), Bound::Unbounded))
+            .map(|(name, _)| name.as_str())
+            .take_while(move |name| name.starts_with(&prefix))
+    }
+
+    /// Returns `true` if the form has a field nested in the given path, like `address[street]` in `address`.
+    pub fn contains_nested(&self, parent: &str, notation: Notation) -> bool {
+        self.nested_names(parent, notation).next().is_some()
+    }
+
+    /// Returns the fields with the given name, or all the fields if there is no name.
+    pub(crate) fn fields_named<'a>(
+        &'a self,
+        name: Option<&str>,
+    ) -> Box<dyn Iterator<Item = &'a MultipartField> + 'a> {
+        match name {
+            Some(name) => Box::new(self.get_all(name)),
+            None => Box::new(self.fields.iter()),
+        }
+    }
+
+    /// Returns the fields with the given name, in the order they were received.
+    fn group(&self, name: &str) -> impl ExactSizeIterator<Item = &MultipartField> + Clone + '_ {
+        let positions = match self.index.get(name) {
+            Some(range) => &self.grouped[range.clone()],
+            None => &[],
+        };
+
+        positions.iter().map(|pos| &self.fields[*pos])
     }
 
     /// Returns all the fields.
@@ -142,7 +445,11 @@ mod tests {
     use http::HeaderValue;
     use multer::Multipart;
 
-    use crate::multipart_form::MultipartForm;
+    use crate::{
+        multipart_form::MultipartForm, ConstraintValue, FieldErrorKind, FormConstraints, FormFile,
+        FromMultipartField, Notation, Storage,
+    };
+    use std::io::Read;
 
     const MULTI_PART_STR: &str = "--MyBoundary\r\nContent-Disposition: form-data; name=\"name\"\r\n\r\nJohn Doe\r\n--MyBoundary\r\nContent-Disposition: form-data; name=\"email\"\r\n\r\njohndoe@example.com\r\n--MyBoundary\r\nContent-Disposition: form-data; name=\"age\"\r\n\r\n25\r\n--MyBoundary\r\nContent-Disposition: form-data; name=\"file\"; filename=\"example.txt\"\r\nContent-Type: text/plain\r\n\r\nThis is an example file.\r\n--MyBoundary--\r\n";
 
@@ -174,6 +481,57 @@ mod tests {
         assert!(form.get(4).is_none());
     }
 
+    #[test]
+    fn name_index_test() {
+        let form_data = "--MyBoundary\r\nContent-Disposition: form-data; name=\"tag\"\r\n\r\nrust\r\n--MyBoundary\r\nContent-Disposition: form-data; name=\"title\"\r\n\r\nHello\r\n--MyBoundary\r\nContent-Disposition: form-data; name=\"tag\"\r\n\r\nweb\r\n--MyBoundary\r\nContent-Disposition: form-data; name=\"tag\"\r\n\r\nforms\r\n--MyBoundary--\r\n";
+        let form = MultipartForm::from_bytes(form_data.as_bytes(), "MyBoundary").unwrap();
+
+        assert!(form.contains("tag"));
+        assert!(!form.contains("author"));
+        assert_eq!(form.count("tag"), 3);
+        assert_eq!(form.count("author"), 0);
+        assert_eq!(form.names().collect::<Vec<_>>(), ["tag", "title"]);
+        assert_eq!(form.get_by_name("tag").unwrap().text(), "rust");
+        assert_eq!(
+            form.get_all("tag").map(|f| f.index()).collect::<Vec<_>>(),
+            [0, 2, 3]
+        );
+
+        // The fields of the groups are the received fields, not copies
+        assert!(std::ptr::eq(form.get_all("tag").nth(1).unwrap(), &form[2]));
+
+        let groups = form
+            .groups()
+            .map(|(name, fields)| (name, fields.map(|f| f.text()).collect::<Vec<_>>()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            groups,
+            [
+                (
+                    "tag",
+                    vec!["rust".to_owned(), "web".to_owned(), "forms".to_owned()]
+                ),
+                ("title", vec!["Hello".to_owned()])
+            ]
+        );
+    }
+
+    #[test]
+    fn nested_names_test() {
+        let form_data = "--MyBoundary\r\nContent-Disposition: form-data; name=\"items[1][name]\"\r\n\r\npen\r\n--MyBoundary\r\nContent-Disposition: form-data; name=\"items\"\r\n\r\nnone\r\n--MyBoundary\r\nContent-Disposition: form-data; name=\"items[0][name]\"\r\n\r\nbook\r\n--MyBoundary\r\nContent-Disposition: form-data; name=\"itemsx[0]\"\r\n\r\nother\r\n--MyBoundary--\r\n";
+        let form = MultipartForm::from_bytes(form_data.as_bytes(), "MyBoundary").unwrap();
+
+        assert_eq!(
+            form.nested_names("items", Notation::Brackets)
+                .collect::<Vec<_>>(),
+            ["items[0][name]", "items[1][name]"]
+        );
+        assert!(form.contains_nested("items", Notation::Brackets));
+        assert!(!form.contains_nested("items", Notation::Dots));
+        assert!(!form.contains_nested("item", Notation::Brackets));
+    }
+
     #[tokio::test]
     async fn form_field_text_test() {
         let reader = MULTI_PART_STR.as_bytes();
@@ -194,6 +552,175 @@ mod tests {
         assert!(form.get(4).is_none());
     }
 
+    #[tokio::test]
+    async fn with_constraints_test() {
+        let constraints = FormConstraints::new()
+            .max_text_size(20)
+            .max_size_for("email", 19)
+            .max_files(1);
+
+        let multipart = Multipart::with_reader(MULTI_PART_STR.as_bytes(), "MyBoundary");
+        let form = MultipartForm::with_constraints(multipart, &constraints)
+            .await
+            .unwrap();
+        assert_eq!(form.len(), 4);
+
+        let constraints = constraints.max_size_for("email", 10);
+        let multipart = Multipart::with_reader(MULTI_PART_STR.as_bytes(), "MyBoundary");
+        let err = MultipartForm::with_constraints(multipart, &constraints)
+            .await
+            .err()
+            .unwrap();
+        let field_error = err.as_field_error().unwrap();
+
+        assert_eq!(field_error.kind(), FieldErrorKind::TooLarge);
+        assert_eq!(field_error.path(), Some("email"));
+        assert_eq!(
+            field_error.constraints().collect::<Vec<_>>(),
+            [("max_size", &ConstraintValue::UInt(10))]
+        );
+    }
+
+    #[tokio::test]
+    async fn with_constraints_count_test() {
+        let multipart = Multipart::with_reader(MULTI_PART_STR.as_bytes(), "MyBoundary");
+        let constraints = FormConstraints::new().max_fields(3);
+        let err = MultipartForm::with_constraints(multipart, &constraints)
+            .await
+            .err()
+            .unwrap();
+
+        assert_eq!(
+            err.to_string(),
+            "`file` form field exceeds the number of fields allowed"
+        );
+
+        let multipart = Multipart::with_reader(MULTI_PART_STR.as_bytes(), "MyBoundary");
+        let constraints = FormConstraints::new().allowed_fields(["name", "email"]);
+        let err = MultipartForm::with_constraints(multipart, &constraints)
+            .await
+            .err()
+            .unwrap();
+
+        assert_eq!(err.to_string(), "`age` form field is not allowed");
+
+        let multipart = Multipart::with_reader(MULTI_PART_STR.as_bytes(), "MyBoundary");
+        let constraints = FormConstraints::new().max_total_size(30);
+        let err = MultipartForm::with_constraints(multipart, &constraints)
+            .await
+            .err()
+            .unwrap();
+
+        assert_eq!(err.status_code(), http::StatusCode::PAYLOAD_TOO_LARGE);
+    }
+
+    #[tokio::test]
+    async fn with_storage_test() {
+        let multipart = Multipart::with_reader(MULTI_PART_STR.as_bytes(), "MyBoundary");
+        let storage = Storage::temp_files(10);
+        let form = MultipartForm::with_storage(multipart, &FormConstraints::new(), &storage)
+            .await
+            .unwrap();
+
+        assert!(form[0].path().is_none());
+        assert_eq!(form[0].text(), "John Doe");
+
+        // Only the files are moved into temporary files
+        assert!(form[1].path().is_none());
+        assert_eq!(form[1].size(), 19);
+        assert_eq!(form[1].text(), "johndoe@example.com");
+
+        let path = form[3].path().unwrap().to_owned();
+        assert!(path.exists());
+        assert_eq!(form[3].try_text().unwrap(), "This is an example file.");
+
+        let mut contents = String::new();
+        form[3]
+            .reader()
+            .unwrap()
+            .read_to_string(&mut contents)
+            .unwrap();
+        assert_eq!(contents, "This is an example file.");
+
+        drop(form);
+        assert!(!path.exists());
+    }
+
+    #[tokio::test]
+    async fn removed_temp_file_test() {
+        let multipart = Multipart::with_reader(MULTI_PART_STR.as_bytes(), "MyBoundary");
+        let storage = Storage::temp_files(0);
+        let form = MultipartForm::with_storage(multipart, &FormConstraints::new(), &storage)
+            .await
+            .unwrap();
+
+        std::fs::remove_file(form[3].path().unwrap()).unwrap();
+
+        assert!(form[3].try_bytes().is_err());
+        assert!(String::from_field(&form[3]).is_err());
+        assert!(FormFile::from_field(&form[3]).unwrap().try_bytes().is_err());
+    }
+
+    #[tokio::test]
+    async fn persist_temp_file_test() {
+        let multipart = Multipart::with_reader(MULTI_PART_STR.as_bytes(), "MyBoundary");
+        let storage = Storage::temp_files(0);
+        let form = MultipartForm::with_storage(multipart, &FormConstraints::new(), &storage)
+            .await
+            .unwrap();
+
+        let temp_path = form[3].path().unwrap().to_owned();
+        let dir = tempfile::tempdir().unwrap();
+        let target = dir.path().join("example.txt");
+
+        let file = FormFile::from_field(&form[3]).unwrap();
+        file.persist(&target).unwrap();
+
+        // The file is renamed while the form is alive, not copied
+        assert!(!temp_path.exists());
+        assert_eq!(form[3].path(), Some(target.as_path()));
+        assert_eq!(form[3].try_text().unwrap(), "This is an example file.");
+
+        drop(form);
+        assert!(target.exists());
+    }
+
+    fn request(
+        content_type: &str,
+    ) -> http::Request<impl futures_core::Stream<Item = Result<&'static [u8], std::io::Error>>>
+    {
+        let body = futures_util::stream::iter([Ok(MULTI_PART_STR.as_bytes())]);
+
+        http::Request::builder()
+            .header(http::header::CONTENT_TYPE, content_type)
+            .body(body)
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn from_request_test() {
+        let form = MultipartForm::from_request(request("multipart/form-data; boundary=MyBoundary"))
+            .await
+            .unwrap();
+
+        assert_eq!(form.len(), 4);
+        assert_eq!(form[3].text(), "This is an example file.");
+    }
+
+    #[tokio::test]
+    async fn from_request_invalid_content_type_test() {
+        let result = MultipartForm::from_request(request("application/json")).await;
+        assert!(matches!(result, Err(multer::Error::NoMultipart)));
+
+        let result = MultipartForm::from_request(request("multipart/form-data")).await;
+        assert!(matches!(result, Err(multer::Error::NoBoundary)));
+
+        let (parts, body) = request("multipart/form-data").into_parts();
+        let result = MultipartForm::from_body(&http::HeaderMap::new(), body).await;
+        assert!(matches!(result, Err(multer::Error::NoMultipart)));
+        drop(parts);
+    }
+
     #[tokio::test]
     async fn form_field_file_test() {
         let reader = MULTI_PART_STR.as_bytes();
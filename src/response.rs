@@ -0,0 +1,213 @@
+use crate::error::{Error, FieldErrorKind};
+use http::StatusCode;
+
+#[cfg(feature = "problem")]
+use crate::error::{ConstraintValue, FieldError};
+
+impl Error {
+    /// Returns the HTTP status code that best describes this error.
+    ///
+    /// - Fields that exceed a size or count limit returns `413 Payload Too Large`.
+    /// - Fields with an unexpected content type returns `415 Unsupported Media Type`.
+    /// - Other field errors returns `422 Unprocessable Entity`.
+    /// - Malformed multipart and other errors returns `400 Bad Request`.
+    pub fn status_code(&self) -> StatusCode {
+        match self {
+            Error::MultipartError(error) => multer_status_code(error),
+            Error::Field(error) => field_status_code(error.kind()),
+            Error::Multiple(errors) => errors
+                .iter()
+                .map(|error| field_status_code(error.kind()))
+                .find(|status| *status != StatusCode::UNPROCESSABLE_ENTITY)
+                .unwrap_or(StatusCode::UNPROCESSABLE_ENTITY),
+            Error::Other(_) => StatusCode::BAD_REQUEST,
+        }
+    }
+}
+
+fn field_status_code(kind: FieldErrorKind) -> StatusCode {
+    match kind {
+        FieldErrorKind::TooLarge | FieldErrorKind::TooMany => StatusCode::PAYLOAD_TOO_LARGE,
+        FieldErrorKind::UnexpectedContentType => StatusCode::UNSUPPORTED_MEDIA_TYPE,
+        _ => StatusCode::UNPROCESSABLE_ENTITY,
+    }
+}
+
+fn multer_status_code(error: &multer::Error) -> StatusCode {
+    match error {
+        multer::Error::FieldSizeExceeded { .. } | multer::Error::StreamSizeExceeded { .. } => {
+            StatusCode::PAYLOAD_TOO_LARGE
+        }
+        multer::Error::NoMultipart | multer::Error::DecodeContentType(_) => {
+            StatusCode::UNSUPPORTED_MEDIA_TYPE
+        }
+        _ => StatusCode::BAD_REQUEST,
+    }
+}
+
+/// The content type of a problem details response.
+#[cfg(feature = "problem")]
+pub const PROBLEM_JSON: &str = "application/problem+json";
+
+#[cfg(feature = "problem")]
+impl Error {
+    /// Returns the RFC 9457 problem details of this error.
+    ///
+    /// Each field error is listed in the `errors` member with the `field` path,
+    /// the `reason` of the error and the `constraints` of the value.
+    pub fn to_problem_details(&self) -> serde_json::Value {
+        let status = self.status_code();
+
+        let mut problem = serde_json::json!({
+            "type": "about:blank",
+            "title": status.canonical_reason().unwrap_or("Bad Request"),
+            "status": status.as_u16(),
+            "detail": self.to_string(),
+        });
+
+        let errors = match self {
+            Error::Field(error) => vec![field_problem(error)],
+            Error::Multiple(errors) => errors.iter().map(field_problem).collect(),
+            Error::MultipartError(multer::Error::FieldSizeExceeded { limit, field_name }) => {
+                let mut error = FieldError::new(FieldErrorKind::TooLarge)
+                    .with_constraint("max_size", *limit)
+                    .with_source(self.to_string());
+
+                if let Some(field_name) = field_name {
+                    error = error.with_path(field_name.as_str());
+                }
+
+                vec![field_problem(&error)]
+            }
+            _ => vec![],
+        };
+
+        if !errors.is_empty() {
+            problem["errors"] = serde_json::Value::Array(errors);
+        }
+
+        problem
+    }
+
+    /// Converts this error to a `application/problem+json` response.
+    pub fn into_response(self) -> http::Response<String> {
+        let body = self.to_problem_details().to_string();
+
+        let mut response = http::Response::new(body);
+        *response.status_mut() = self.status_code();
+        response.headers_mut().insert(
+            http::header::CONTENT_TYPE,
+            http::HeaderValue::from_static(PROBLEM_JSON),
+        );
+
+        response
+    }
+}
+
+#[cfg(feature = "problem")]
+fn field_problem(error: &FieldError) -> serde_json::Value {
+    let constraints = error
+        .constraints()
+        .map(|(name, value)| {
+            let value = match value {
+                ConstraintValue::UInt(value) => serde_json::Value::from(*value),
+                ConstraintValue::Int(value) => serde_json::Value::from(*value),
+                ConstraintValue::Float(value) => serde_json::Value::from(*value),
+                ConstraintValue::Bool(value) => serde_json::Value::from(*value),
+                ConstraintValue::Text(value) => serde_json::Value::from(value.as_str()),
+            };
+
+            (name.to_owned(), value)
+        })
+        .collect::<serde_json::Map<_, _>>();
+
+    serde_json::json!({
+        "field": error.path().or(error.name()),
+        "reason": error.kind().as_str(),
+        "detail": error.to_string(),
+        "constraints": constraints,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Error, FieldError, FieldErrorKind};
+    use http::StatusCode;
+
+    #[test]
+    fn status_code_test() {
+        let missing = Error::from(FieldError::new(FieldErrorKind::Missing));
+        let too_large = Error::from(FieldError::new(FieldErrorKind::TooLarge));
+        let stream = Error::from(multer::Error::StreamSizeExceeded { limit: 10 });
+        let malformed = Error::from(multer::Error::IncompleteStream);
+
+        assert_eq!(missing.status_code(), StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(too_large.status_code(), StatusCode::PAYLOAD_TOO_LARGE);
+        assert_eq!(stream.status_code(), StatusCode::PAYLOAD_TOO_LARGE);
+        assert_eq!(malformed.status_code(), StatusCode::BAD_REQUEST);
+    }
+
+    #[test]
+    fn multiple_status_code_test() {
+        let mut errors = crate::Errors::new();
+        errors.push(FieldError::new(FieldErrorKind::Missing));
+        errors.push(FieldError::new(FieldErrorKind::TooLarge));
+
+        assert_eq!(
+            Error::from(errors).status_code(),
+            StatusCode::PAYLOAD_TOO_LARGE
+        );
+    }
+
+    #[cfg(feature = "problem")]
+    #[test]
+    fn problem_details_test() {
+        let error = Error::from(
+            FieldError::new(FieldErrorKind::TooLarge)
+                .with_name("avatar")
+                .with_path("user[avatar]")
+                .with_constraint("max_size", 1024),
+        );
+
+        let response = error.into_response();
+        assert_eq!(response.status(), StatusCode::PAYLOAD_TOO_LARGE);
+        assert_eq!(
+            response.headers()[http::header::CONTENT_TYPE],
+            super::PROBLEM_JSON
+        );
+
+        let body: serde_json::Value = serde_json::from_str(response.body()).unwrap();
+        assert_eq!(
+            body,
+            serde_json::json!({
+                "type": "about:blank",
+                "title": "Payload Too Large",
+                "status": 413,
+                "detail": "`user[avatar]` form field is too large",
+                "errors": [{
+                    "field": "user[avatar]",
+                    "reason": "too_large",
+                    "detail": "`user[avatar]` form field is too large",
+                    "constraints": { "max_size": 1024 }
+                }]
+            })
+        );
+    }
+    #[cfg(feature = "problem")]
+    #[test]
+    fn problem_details_constraints_test() {
+        let error = Error::from(
+            FieldError::new(FieldErrorKind::Validation)
+                .with_name("code")
+                .with_constraint("pattern", "123")
+                .with_constraint("min", -1)
+                .with_constraint("max", 1.5),
+        );
+
+        let body = error.to_problem_details();
+        assert_eq!(
+            body["errors"][0]["constraints"],
+            serde_json::json!({ "pattern": "123", "min": -1, "max": 1.5 })
+        );
+    }
+}
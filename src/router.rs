@@ -0,0 +1,364 @@
+use crate::{
+    error::{Error, FieldError, FieldErrorKind},
+    form_file::{FieldMetadata, FormFile},
+    from_multipart_field::FromMultipartField,
+    multipart_form::MultipartField,
+};
+use futures_core::Stream;
+use http::HeaderMap;
+use mime::Mime;
+use multer::{bytes::Bytes, Field, Multipart};
+use std::{
+    collections::HashMap,
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+type BoxFuture<'r, T> = Pin<Box<dyn Future<Output = Result<T, Error>> + Send + 'r>>;
+type Handler<'r, T> = Box<dyn Fn(FieldStream<'r>) -> BoxFuture<'r, T> + Send + Sync + 'r>;
+
+/// Routes each field of a [`multer::Multipart`] to an async handler registered for its name,
+/// so the fields can be streamed to their destination without holding them in memory.
+///
+/// All the handlers return the same type `T`, use an enum to return different values.
+/// Fields without a handler are skipped.
+///
+/// # Example
+/// ```rs
+/// let router = FieldRouter::new()
+///     .route("avatar", |field| async move { save_to_disk(field).await })
+///     .route("dataset", |field| async move { parse_csv(field).await });
+///
+/// let mut fields = router.run(multipart).await?;
+/// let avatar = fields.take_required("avatar")?;
+/// ```
+pub struct FieldRouter<'r, T> {
+    routes: HashMap<String, Handler<'r, T>>,
+    fallback: Option<Handler<'r, T>>,
+}
+
+impl<'r, T: Send + 'r> FieldRouter<'r, T> {
+    /// Constructs a router without any handler.
+    pub fn new() -> Self {
+        FieldRouter {
+            routes: HashMap::new(),
+            fallback: None,
+        }
+    }
+
+    /// Registers the handler of the fields with the given name.
+    pub fn route<F, Fut>(mut self, name: impl Into<String>, handler: F) -> Self
+    where
+        F: Fn(FieldStream<'r>) -> Fut + Send + Sync + 'r,
+        Fut: Future<Output = Result<T, Error>> + Send + 'r,
+    {
+        self.routes.insert(name.into(), boxed(handler));
+        self
+    }
+
+    /// Registers the handler of the fields without a route.
+    pub fn fallback<F, Fut>(mut self, handler: F) -> Self
+    where
+        F: Fn(FieldStream<'r>) -> Fut + Send + Sync + 'r,
+        Fut: Future<Output = Result<T, Error>> + Send + 'r,
+    {
+        self.fallback = Some(boxed(handler));
+        self
+    }
+
+    /// Reads the fields of the multipart, calling the handler of each field as it arrives.
+    ///
+    /// Returns the first error of a handler, attached to the field that failed.
+    /// The errors of the stream are returned as is.
+    pub async fn run(&self, mut multipart: Multipart<'r>) -> Result<RoutedFields<T>, Error> {
+        let mut fields = Vec::new();
+
+        while let Some(field) = multipart.next_field().await? {
+            let Some(name) = field.name().map(|s| s.to_owned()) else {
+                continue;
+            };
+
+            let Some(handler) = self.routes.get(&name).or(self.fallback.as_ref()) else {
+                continue;
+            };
+
+            let index = field.index();
+            let value = handler(FieldStream::new(field))
+                .await
+                .map_err(|err| err.for_field(&name, &name, Some(index)))?;
+
+            fields.push((name, value));
+        }
+
+        Ok(RoutedFields { fields })
+    }
+}
+
+impl<'r, T: Send + 'r> Default for FieldRouter<'r, T> {
+    fn default() -> Self {
+        FieldRouter::new()
+    }
+}
+
+impl<T> std::fmt::Debug for FieldRouter<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FieldRouter")
+            .field("routes", &self.routes.keys().collect::<Vec<_>>())
+            .field("fallback", &self.fallback.is_some())
+            .finish()
+    }
+}
+
+fn boxed<'r, T, F, Fut>(handler: F) -> Handler<'r, T>
+where
+    F: Fn(FieldStream<'r>) -> Fut + Send + Sync + 'r,
+    Fut: Future<Output = Result<T, Error>> + Send + 'r,
+{
+    Box::new(move |field| Box::pin(handler(field)))
+}
+
+/// A field received by a [`FieldRouter`] handler, which contents are read chunk by chunk.
+///
+/// Is also a [`Stream`] of the chunks of the field.
+#[derive(Debug)]
+pub struct FieldStream<'r> {
+    field: Field<'r>,
+    metadata: FieldMetadata,
+}
+
+impl<'r> FieldStream<'r> {
+    fn new(field: Field<'r>) -> Self {
+        let metadata = FieldMetadata::new(
+            field.name(),
+            field.file_name(),
+            field.content_type(),
+            field.headers(),
+        );
+
+        FieldStream { field, metadata }
+    }
+
+    /// Returns the name of the form field.
+    pub fn name(&self) -> &str {
+        self.metadata.name()
+    }
+
+    /// Returns the file name, if the field is a file.
+    pub fn file_name(&self) -> Option<&str> {
+        self.metadata.file_name()
+    }
+
+    /// Returns the content type of the field, `application/octet-stream` if was not sent.
+    pub fn content_type(&self) -> &Mime {
+        self.metadata.content_type()
+    }
+
+    /// Returns the headers of the field.
+    pub fn headers(&self) -> &HeaderMap {
+        self.metadata.headers()
+    }
+
+    /// Returns the index of the field in the form.
+    pub fn index(&self) -> usize {
+        self.field.index()
+    }
+
+    /// Returns the next chunk of the field, or `None` once the field was read.
+    pub async fn chunk(&mut self) -> Result<Option<Bytes>, Error> {
+        Ok(self.field.chunk().await?)
+    }
+
+    /// Reads the whole field into memory and parses it as any other [`FromMultipartField`].
+    pub async fn parse<T: FromMultipartField>(self) -> Result<T, Error> {
+        let field = MultipartField::read(self.field).await?;
+        T::from_field(&field)
+    }
+
+    /// Reads the whole file into memory.
+    pub async fn into_form_file(self) -> Result<FormFile, Error> {
+        self.parse().await
+    }
+
+    /// Returns the underlying [`multer::Field`].
+    pub fn into_inner(self) -> Field<'r> {
+        self.field
+    }
+}
+
+impl Stream for FieldStream<'_> {
+    type Item = Result<Bytes, Error>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        Pin::new(&mut self.get_mut().field)
+            .poll_next(cx)
+            .map(|chunk| chunk.map(|chunk| chunk.map_err(Error::from)))
+    }
+}
+
+/// The values returned by the handlers of a [`FieldRouter`], in the order the fields were received.
+#[derive(Debug, Clone)]
+pub struct RoutedFields<T> {
+    fields: Vec<(String, T)>,
+}
+
+impl<T> RoutedFields<T> {
+    /// Returns the value of the first field with the given name.
+    pub fn get(&self, name: &str) -> Option<&T> {
+        self.fields
+            .iter()
+            .find(|(field_name, _)| field_name == name)
+            .map(|(_, value)| value)
+    }
+
+    /// Returns the values of all the fields with the given name.
+    pub fn get_all<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a T> + 'a {
+        self.fields
+            .iter()
+            .filter(move |(field_name, _)| field_name == name)
+            .map(|(_, value)| value)
+    }
+
+    /// Removes and returns the value of the first field with the given name.
+    pub fn take(&mut self, name: &str) -> Option<T> {
+        let pos = self
+            .fields
+            .iter()
+            .position(|(field_name, _)| field_name == name)?;
+
+        Some(self.fields.remove(pos).1)
+    }
+
+    /// Removes and returns the value of the first field with the given name,
+    /// returns a [`FieldErrorKind::Missing`] error if was not received.
+    pub fn take_required(&mut self, name: &str) -> Result<T, Error> {
+        self.take(name).ok_or_else(|| {
+            FieldError::new(FieldErrorKind::Missing)
+                .with_name(name)
+                .with_path(name)
+                .into()
+        })
+    }
+
+    /// Removes and returns the values of all the fields with the given name.
+    pub fn take_all(&mut self, name: &str) -> Vec<T> {
+        let (taken, fields) = std::mem::take(&mut self.fields)
+            .into_iter()
+            .partition::<Vec<_>, _>(|(field_name, _)| field_name == name);
+
+        self.fields = fields;
+        taken.into_iter().map(|(_, value)| value).collect()
+    }
+
+    /// Returns the number of values.
+    pub fn len(&self) -> usize {
+        self.fields.len()
+    }
+
+    /// Returns `true` if there are no values.
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+
+    /// Returns an iterator over the names of the fields and its values.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &T)> {
+        self.fields
+            .iter()
+            .map(|(name, value)| (name.as_str(), value))
+    }
+}
+
+impl<T> IntoIterator for RoutedFields<T> {
+    type Item = (String, T);
+    type IntoIter = std::vec::IntoIter<(String, T)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.fields.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FieldRouter;
+    use crate::{Error, FieldErrorKind};
+    use futures_util::StreamExt;
+    use multer::Multipart;
+
+    const FORM: &str = "--MyBoundary\r\n\
+        Content-Disposition: form-data; name=\"avatar\"; filename=\"avatar.png\"\r\n\
+        Content-Type: image/png\r\n\
+        \r\n\
+        PNG DATA\r\n\
+        --MyBoundary\r\n\
+        Content-Disposition: form-data; name=\"dataset\"; filename=\"data.csv\"\r\n\
+        \r\n\
+        a,b\n1,2\n3,4\r\n\
+        --MyBoundary\r\n\
+        Content-Disposition: form-data; name=\"title\"\r\n\
+        \r\n\
+        Hello\r\n\
+        --MyBoundary--\r\n";
+
+    #[derive(Debug, PartialEq)]
+    enum Upload {
+        Size(String, usize),
+        Rows(usize),
+        Text(String),
+    }
+
+    #[tokio::test]
+    async fn router_test() {
+        let router = FieldRouter::new()
+            .route("avatar", |mut field| async move {
+                assert_eq!(field.content_type(), &mime::IMAGE_PNG);
+                let file_name = field.file_name().unwrap().to_owned();
+                let mut size = 0;
+
+                while let Some(chunk) = field.chunk().await? {
+                    size += chunk.len();
+                }
+
+                Ok(Upload::Size(file_name, size))
+            })
+            .route("dataset", |mut field| async move {
+                assert_eq!(field.content_type(), &mime::APPLICATION_OCTET_STREAM);
+                let mut text = Vec::new();
+
+                while let Some(chunk) = field.next().await {
+                    text.extend_from_slice(&chunk?);
+                }
+
+                let text = String::from_utf8(text).map_err(Error::new)?;
+                Ok(Upload::Rows(text.lines().count() - 1))
+            })
+            .fallback(|field| async move { field.parse().await.map(Upload::Text) });
+
+        let multipart = Multipart::with_reader(FORM.as_bytes(), "MyBoundary");
+        let mut fields = router.run(multipart).await.unwrap();
+
+        assert_eq!(fields.len(), 3);
+        assert_eq!(fields.get("title"), Some(&Upload::Text("Hello".to_owned())));
+        assert_eq!(
+            fields.take_required("avatar").unwrap(),
+            Upload::Size("avatar.png".to_owned(), 8)
+        );
+        assert_eq!(fields.take_all("dataset"), [Upload::Rows(2)]);
+        assert!(fields.take_required("avatar").is_err());
+        assert_eq!(fields.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn router_error_test() {
+        let router = FieldRouter::<()>::new()
+            .route("title", |_| async move { Ok(()) })
+            .route("dataset", |_| async move { Err(Error::new("invalid csv")) });
+
+        let multipart = Multipart::with_reader(FORM.as_bytes(), "MyBoundary");
+        let error = router.run(multipart).await.unwrap_err();
+        let error = error.as_field_error().unwrap();
+
+        assert_eq!(error.kind(), FieldErrorKind::Parse);
+        assert_eq!(error.path(), Some("dataset"));
+        assert_eq!(error.index(), Some(1));
+    }
+}
@@ -0,0 +1,242 @@
+use crate::FormFile;
+use std::{
+    fs::File,
+    io::{self, ErrorKind},
+    path::{Component, Path, PathBuf},
+};
+use tempfile::NamedTempFile;
+
+/// The max number of names tried by [`SaveOptions::unique_name`].
+const MAX_UNIQUE_NAMES: usize = 1000;
+
+/// Options used to save a file with [`FormFile::save_to`].
+///
+/// By default saving a file fails if the target file already exists.
+#[derive(Debug, Clone, Default)]
+pub struct SaveOptions {
+    overwrite: bool,
+    sync: bool,
+    unique_name: bool,
+    file_name: Option<String>,
+}
+
+impl SaveOptions {
+    /// Constructs the default options.
+    pub fn new() -> Self {
+        SaveOptions::default()
+    }
+
+    /// Replaces the target file if already exists.
+    pub fn overwrite(mut self, overwrite: bool) -> Self {
+        self.overwrite = overwrite;
+        self
+    }
+
+    /// Flushes the file and the directory to disk before returning.
+    pub fn sync(mut self, sync: bool) -> Self {
+        self.sync = sync;
+        self
+    }
+
+    /// Appends a number to the file name if the target file already exists, like `photo-1.png`.
+    pub fn unique_name(mut self, unique_name: bool) -> Self {
+        self.unique_name = unique_name;
+        self
+    }
+
+    /// Sets the name of the saved file, by default [`FormFile::file_name`] is used.
+    pub fn file_name(mut self, file_name: impl Into<String>) -> Self {
+        self.file_name = Some(file_name.into());
+        self
+    }
+}
+
+impl FormFile {
+    /// Saves this file into the given directory and returns the path of the saved file.
+    ///
+    /// The contents are written to a temporary file in the directory which is then renamed,
+    /// so the target file is never partially written.
+    ///
+    /// # Errors
+    /// Returns an error of kind [`ErrorKind::InvalidInput`] if the file name is not a plain name,
+    /// like an absolute path or a path containing `..`, and [`ErrorKind::AlreadyExists`]
+    /// if the target file exists and the options do not allow to replace it.
+    pub fn save_to(&self, dir: impl AsRef<Path>, options: &SaveOptions) -> io::Result<PathBuf> {
+        let dir = dir.as_ref();
+        let file_name = options.file_name.as_deref().unwrap_or(self.file_name());
+        check_file_name(file_name)?;
+
+        let mut temp_file = NamedTempFile::new_in(dir)?;
+        io::copy(&mut self.reader()?, &mut temp_file)?;
+
+        if options.sync {
+            temp_file.as_file().sync_all()?;
+        }
+
+        let mut target = dir.join(file_name);
+        let mut attempt = 0;
+
+        let path = loop {
+            let result = match options.overwrite {
+                true => temp_file.persist(&target),
+                false => temp_file.persist_noclobber(&target),
+            };
+
+            match result {
+                Ok(_) => break target,
+                Err(err)
+                    if err.error.kind() == ErrorKind::AlreadyExists
+                        && options.unique_name
+                        && attempt < MAX_UNIQUE_NAMES =>
+                {
+                    attempt += 1;
+                    target = dir.join(numbered_file_name(file_name, attempt));
+                    temp_file = err.file;
+                }
+                Err(err) => return Err(err.error),
+            }
+        };
+
+        if options.sync {
+            sync_dir(dir)?;
+        }
+
+        Ok(path)
+    }
+
+    /// Saves this file into the given directory without blocking the async runtime,
+    /// see [`FormFile::save_to`].
+    #[cfg(feature = "tokio")]
+    pub async fn save_to_async(
+        &self,
+        dir: impl AsRef<Path>,
+        options: &SaveOptions,
+    ) -> io::Result<PathBuf> {
+        let file = self.clone();
+        let dir = dir.as_ref().to_owned();
+        let options = options.clone();
+
+        tokio::task::spawn_blocking(move || file.save_to(dir, &options))
+            .await
+            .map_err(io::Error::other)?
+    }
+}
+
+/// Checks the file name is a single plain name which cannot escape the target directory.
+fn check_file_name(file_name: &str) -> io::Result<()> {
+    let mut components = Path::new(file_name).components();
+
+    match (components.next(), components.next()) {
+        (Some(Component::Normal(_)), None) => Ok(()),
+        _ => Err(io::Error::new(
+            ErrorKind::InvalidInput,
+            format!("invalid file name `{file_name}`"),
+        )),
+    }
+}
+
+/// Returns the file name with the given number before the extension.
+fn numbered_file_name(file_name: &str, n: usize) -> String {
+    match file_name.rsplit_once('.') {
+        Some((stem, ext)) if !stem.is_empty() => format!("{stem}-{n}.{ext}"),
+        _ => format!("{file_name}-{n}"),
+    }
+}
+
+#[cfg(unix)]
+fn sync_dir(dir: &Path) -> io::Result<()> {
+    File::open(dir)?.sync_all()
+}
+
+#[cfg(not(unix))]
+fn sync_dir(_dir: &Path) -> io::Result<()> {
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{check_file_name, numbered_file_name, SaveOptions};
+    use crate::{FormFile, FromMultipartField, MultipartForm};
+    use multer::Multipart;
+    use std::io::ErrorKind;
+
+    const FORM: &str = "--MyBoundary\r\n\
+        Content-Disposition: form-data; name=\"file\"; filename=\"example.txt\"\r\n\
+        Content-Type: text/plain\r\n\
+        \r\n\
+        Hello World\r\n\
+        --MyBoundary--\r\n";
+
+    async fn form_file(form: &str) -> FormFile {
+        let multipart = Multipart::with_reader(form.as_bytes(), "MyBoundary");
+        let form = MultipartForm::with_multipart(multipart).await.unwrap();
+        FormFile::from_field(&form[0]).unwrap()
+    }
+
+    #[test]
+    fn check_file_name_test() {
+        assert!(check_file_name("photo.png").is_ok());
+        assert!(check_file_name("/etc/passwd").is_err());
+        assert!(check_file_name("../photo.png").is_err());
+        assert!(check_file_name("images/../../photo.png").is_err());
+        assert!(check_file_name("..").is_err());
+        assert!(check_file_name("").is_err());
+    }
+
+    #[test]
+    fn numbered_file_name_test() {
+        assert_eq!(numbered_file_name("photo.png", 1), "photo-1.png");
+        assert_eq!(numbered_file_name("archive.tar.gz", 2), "archive.tar-2.gz");
+        assert_eq!(numbered_file_name(".env", 1), ".env-1");
+        assert_eq!(numbered_file_name("README", 3), "README-3");
+    }
+
+    #[tokio::test]
+    async fn save_to_test() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = form_file(FORM).await;
+
+        let path = file.save_to(dir.path(), &SaveOptions::new()).unwrap();
+        assert_eq!(path, dir.path().join("example.txt"));
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "Hello World");
+
+        let error = file.save_to(dir.path(), &SaveOptions::new()).unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::AlreadyExists);
+
+        let options = SaveOptions::new().overwrite(true).sync(true);
+        assert_eq!(file.save_to(dir.path(), &options).unwrap(), path);
+
+        let options = SaveOptions::new().unique_name(true);
+        let path = file.save_to(dir.path(), &options).unwrap();
+        assert_eq!(path, dir.path().join("example-1.txt"));
+
+        // No temporary files are left behind
+        assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 2);
+    }
+
+    #[tokio::test]
+    async fn save_to_invalid_file_name_test() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = form_file(&FORM.replace("example.txt", "../example.txt")).await;
+
+        let error = file.save_to(dir.path(), &SaveOptions::new()).unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::InvalidInput);
+
+        let options = SaveOptions::new().file_name("example.txt");
+        assert!(file.save_to(dir.path(), &options).is_ok());
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn save_to_async_test() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = form_file(FORM).await;
+
+        let path = file
+            .save_to_async(dir.path(), &SaveOptions::new())
+            .await
+            .unwrap();
+
+        assert_eq!(std::fs::read_to_string(path).unwrap(), "Hello World");
+    }
+}
@@ -0,0 +1,354 @@
+use multer::bytes::{Bytes, BytesMut};
+use std::{
+    fs::File,
+    io::{Cursor, Read, Write},
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex, OnceLock, PoisonError},
+};
+use tempfile::NamedTempFile;
+
+/// Where the fields of a multipart form are stored while is being read.
+///
+/// By default all the fields are kept in memory, with [`Storage::temp_files`] the files
+/// larger than a threshold are streamed into temporary files which are removed on drop.
+#[derive(Debug, Clone, Default)]
+pub struct Storage {
+    threshold: Option<u64>,
+    dir: Option<PathBuf>,
+}
+
+impl Storage {
+    /// Keeps all the fields in memory.
+    pub fn memory() -> Self {
+        Storage::default()
+    }
+
+    /// Streams the files larger than the given size in bytes into temporary files,
+    /// the text fields are always kept in memory.
+    pub fn temp_files(threshold: u64) -> Self {
+        Storage {
+            threshold: Some(threshold),
+            dir: None,
+        }
+    }
+
+    /// Sets the directory of the temporary files, by default [`std::env::temp_dir`] is used.
+    pub fn in_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.dir = Some(dir.into());
+        self
+    }
+
+    /// Returns an empty buffer for the next field, only files are moved into temporary files.
+    pub(crate) fn buffer(&self, is_file: bool) -> FieldBuffer<'_> {
+        FieldBuffer {
+            storage: self,
+            threshold: self.threshold.filter(|_| is_file),
+            memory: BytesMut::new(),
+            file: None,
+        }
+    }
+}
+
+/// Collects the chunks of a field, moving them into a temporary file once exceed the threshold.
+///
+/// With the `tokio` feature the temporary file is written in the blocking thread pool of the runtime.
+pub(crate) struct FieldBuffer<'a> {
+    storage: &'a Storage,
+    threshold: Option<u64>,
+    memory: BytesMut,
+    file: Option<(NamedTempFile, u64)>,
+}
+
+impl FieldBuffer<'_> {
+    /// Returns the number of bytes written.
+    pub fn len(&self) -> u64 {
+        match &self.file {
+            Some((_, size)) => *size,
+            None => self.memory.len() as u64,
+        }
+    }
+
+    /// Writes the next chunk of the field.
+    pub async fn write(&mut self, chunk: Bytes) -> std::io::Result<()> {
+        if let Some((mut file, size)) = self.file.take() {
+            let len = chunk.len() as u64;
+            let file = blocking(move || {
+                file.write_all(&chunk)?;
+                Ok(file)
+            })
+            .await?;
+
+            self.file = Some((file, size + len));
+            return Ok(());
+        }
+
+        let exceeds = self
+            .threshold
+            .is_some_and(|threshold| (self.memory.len() + chunk.len()) as u64 > threshold);
+
+        if !exceeds {
+            self.memory.extend_from_slice(&chunk);
+            return Ok(());
+        }
+
+        let memory = std::mem::take(&mut self.memory).freeze();
+        let size = (memory.len() + chunk.len()) as u64;
+        let dir = self.storage.dir.clone();
+
+        let file = blocking(move || {
+            let mut file = match dir {
+                Some(dir) => NamedTempFile::new_in(dir)?,
+                None => NamedTempFile::new()?,
+            };
+
+            file.write_all(&memory)?;
+            file.write_all(&chunk)?;
+            Ok(file)
+        })
+        .await?;
+
+        self.file = Some((file, size));
+        Ok(())
+    }
+
+    /// Returns the data of the field.
+    pub async fn finish(self) -> std::io::Result<FieldData> {
+        match self.file {
+            Some((mut file, size)) => {
+                let path = blocking(move || {
+                    file.flush()?;
+                    file.into_temp_path().keep().map_err(|err| err.error)
+                })
+                .await?;
+
+                Ok(FieldData::File(Arc::new(TempFile {
+                    path,
+                    persisted: OnceLock::new(),
+                    lock: Mutex::new(()),
+                    size,
+                    bytes: OnceLock::new(),
+                })))
+            }
+            None => Ok(FieldData::Memory(self.memory.freeze())),
+        }
+    }
+}
+
+/// Runs a blocking file operation, in the blocking thread pool of `tokio` if is called within a runtime.
+async fn blocking<T, F>(f: F) -> std::io::Result<T>
+where
+    T: Send + 'static,
+    F: FnOnce() -> std::io::Result<T> + Send + 'static,
+{
+    #[cfg(feature = "tokio")]
+    if let Ok(handle) = tokio::runtime::Handle::try_current() {
+        return handle
+            .spawn_blocking(f)
+            .await
+            .map_err(std::io::Error::other)?;
+    }
+
+    f()
+}
+
+/// The contents of a field, in memory or in a temporary file.
+#[derive(Debug, Clone)]
+pub(crate) enum FieldData {
+    Memory(Bytes),
+    File(Arc<TempFile>),
+}
+
+impl FieldData {
+    /// Returns the bytes of the field, a temporary file is read into memory the first time.
+    pub fn try_bytes(&self) -> std::io::Result<&Bytes> {
+        match self {
+            FieldData::Memory(bytes) => Ok(bytes),
+            FieldData::File(file) => {
+                if let Some(bytes) = file.bytes.get() {
+                    return Ok(bytes);
+                }
+
+                let contents = std::fs::read(file.path())?;
+                Ok(file.bytes.get_or_init(|| Bytes::from(contents)))
+            }
+        }
+    }
+
+    /// Returns the size of the field in bytes.
+    pub fn len(&self) -> u64 {
+        match self {
+            FieldData::Memory(bytes) => bytes.len() as u64,
+            FieldData::File(file) => file.size,
+        }
+    }
+
+    /// Returns the path of the temporary file, if any.
+    pub fn path(&self) -> Option<&Path> {
+        match self {
+            FieldData::Memory(_) => None,
+            FieldData::File(file) => Some(file.path()),
+        }
+    }
+
+    /// Returns a reader over the contents of the field.
+    pub fn reader(&self) -> std::io::Result<Box<dyn Read + Send + Sync + '_>> {
+        match self {
+            FieldData::Memory(bytes) => Ok(Box::new(Cursor::new(bytes.as_ref()))),
+            FieldData::File(file) => Ok(Box::new(File::open(file.path())?)),
+        }
+    }
+
+    /// Moves the contents of the field to the given path, see [`TempFile::persist`].
+    pub fn persist(self, target: &Path) -> std::io::Result<()> {
+        match self {
+            FieldData::Memory(bytes) => std::fs::write(target, bytes),
+            FieldData::File(file) => file.persist(target),
+        }
+    }
+}
+
+/// A field stored in a temporary file, shared by the form and the values parsed from the field.
+///
+/// The file is removed when dropped, unless it was moved with [`TempFile::persist`].
+#[derive(Debug)]
+pub(crate) struct TempFile {
+    path: PathBuf,
+    // The path where the file was moved
+    persisted: OnceLock<PathBuf>,
+    // Only one call to `persist` can move the file
+    lock: Mutex<()>,
+    size: u64,
+    bytes: OnceLock<Bytes>,
+}
+
+impl TempFile {
+    /// Returns the current path of the file.
+    fn path(&self) -> &Path {
+        self.persisted.get().unwrap_or(&self.path)
+    }
+
+    /// Moves the file to the given path, the form and the other values of the field
+    /// then read the file from the new path and no longer remove it.
+    ///
+    /// The file is renamed the first time, if the rename fails, like across file systems,
+    /// or the file was already moved, the contents are copied instead.
+    fn persist(&self, target: &Path) -> std::io::Result<()> {
+        let _guard = self.lock.lock().unwrap_or_else(PoisonError::into_inner);
+
+        if let Some(current) = self.persisted.get() {
+            return std::fs::copy(current, target).map(|_| ());
+        }
+
+        match std::fs::rename(&self.path, target) {
+            Ok(()) => {
+                let _ = self.persisted.set(target.to_owned());
+                Ok(())
+            }
+            Err(_) => std::fs::copy(&self.path, target).map(|_| ()),
+        }
+    }
+}
+
+impl Drop for TempFile {
+    fn drop(&mut self) {
+        if self.persisted.get().is_none() {
+            let _ = std::fs::remove_file(&self.path);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Storage;
+    use multer::bytes::Bytes;
+
+    #[tokio::test]
+    async fn buffer_spills_to_file_test() {
+        let storage = Storage::temp_files(8);
+        let mut buffer = storage.buffer(true);
+
+        buffer.write(Bytes::from_static(b"hello")).await.unwrap();
+        assert!(buffer.file.is_none());
+
+        buffer.write(Bytes::from_static(b" world")).await.unwrap();
+        assert_eq!(buffer.len(), 11);
+
+        let data = buffer.finish().await.unwrap();
+        let path = data.path().unwrap().to_owned();
+
+        assert!(path.exists());
+        assert_eq!(data.try_bytes().unwrap().as_ref(), b"hello world");
+
+        drop(data);
+        assert!(!path.exists());
+    }
+
+    #[tokio::test]
+    async fn buffer_text_in_memory_test() {
+        let storage = Storage::temp_files(8);
+        let mut buffer = storage.buffer(false);
+        buffer
+            .write(Bytes::from_static(b"hello world"))
+            .await
+            .unwrap();
+
+        let data = buffer.finish().await.unwrap();
+        assert!(data.path().is_none());
+        assert_eq!(data.len(), 11);
+    }
+
+    #[tokio::test]
+    async fn removed_file_error_test() {
+        let storage = Storage::temp_files(0);
+        let mut buffer = storage.buffer(true);
+        buffer
+            .write(Bytes::from_static(b"hello world"))
+            .await
+            .unwrap();
+
+        let data = buffer.finish().await.unwrap();
+        std::fs::remove_file(data.path().unwrap()).unwrap();
+
+        assert!(data.try_bytes().is_err());
+    }
+
+    #[tokio::test]
+    async fn persist_renames_shared_file_test() {
+        let storage = Storage::temp_files(0);
+        let mut buffer = storage.buffer(true);
+        buffer
+            .write(Bytes::from_static(b"hello world"))
+            .await
+            .unwrap();
+
+        let data = buffer.finish().await.unwrap();
+        let shared = data.clone();
+        let temp_path = data.path().unwrap().to_owned();
+
+        let dir = tempfile::tempdir().unwrap();
+        let target = dir.path().join("hello.txt");
+        data.persist(&target).unwrap();
+
+        // The file is moved even if the data is shared, and the other values read it from the new path
+        assert!(!temp_path.exists());
+        assert_eq!(shared.path(), Some(target.as_path()));
+        assert_eq!(shared.try_bytes().unwrap().as_ref(), b"hello world");
+
+        drop(shared);
+        assert!(target.exists());
+    }
+
+    #[tokio::test]
+    async fn buffer_in_memory_test() {
+        let storage = Storage::memory();
+        let mut buffer = storage.buffer(true);
+        buffer
+            .write(Bytes::from_static(b"hello world"))
+            .await
+            .unwrap();
+
+        let data = buffer.finish().await.unwrap();
+        assert!(data.path().is_none());
+        assert_eq!(data.len(), 11);
+    }
+}
@@ -0,0 +1,509 @@
+//! Validators used by `#[multer(validate(...))]`.
+//!
+//! Each validator returns a [`FieldError`] of kind [`FieldErrorKind::Validation`]
+//! with the constraints that were not satisfied, the derive sets the name and path of the field.
+
+use crate::{
+    error::{ConstraintValue, Error, Errors, FieldError, FieldErrorKind},
+    FormContext, FormFile, FromMultipart, MultipartForm,
+};
+use std::{fmt::Display, future::Future};
+
+/// The `Regex` used by `#[multer(validate(regex = "..."))]` with a pattern.
+#[cfg(feature = "regex")]
+#[doc(hidden)]
+pub use regex::Regex;
+
+/// A validation of the whole value that requires I/O, like checking a username is not taken.
+///
+/// Errors with only a field name are attached to that field, see [`FormContext::scope`].
+pub trait AsyncValidate<S: ?Sized = ()> {
+    /// Validates this value using the given state.
+    fn validate(&self, state: &S) -> impl Future<Output = Result<(), Error>> + Send;
+}
+
+/// Parses a value from the form and then runs its [`AsyncValidate`] validation.
+pub async fn parse_and_validate<T, S>(
+    multipart: &MultipartForm,
+    ctx: FormContext<'_>,
+    state: &S,
+) -> Result<T, Error>
+where
+    T: FromMultipart + AsyncValidate<S>,
+    S: ?Sized,
+{
+    let value = T::from_multipart(multipart, ctx.clone())?;
+    value.validate(state).await.map_err(|err| ctx.scope(err))?;
+    Ok(value)
+}
+
+/// Returns a validation error for the field with the given name, used for custom validations.
+pub fn error(name: &str, message: impl Into<String>) -> FieldError {
+    validation_error(message).with_name(name)
+}
+
+/// Returns the validation errors for each field name and message, used for custom validations.
+///
+/// With the `validator` or `garde` features the errors of those crates are translated
+/// by [`validator_errors`] and [`garde_errors`] instead.
+pub fn errors<I, N, M>(messages: I) -> Errors
+where
+    I: IntoIterator<Item = (N, M)>,
+    N: AsRef<str>,
+    M: Display,
+{
+    let mut errors = Errors::new();
+
+    for (name, message) in messages {
+        errors.push(error(name.as_ref(), message.to_string()));
+    }
+
+    errors
+}
+
+/// Translates the errors of [`validator::Validate`] into field errors.
+///
+/// The errors of nested structs and lists are joined to the `prefix` of the context
+/// with its notation, like `address[street]` or `items[0][name]`. The errors of the whole value,
+/// like the ones of `#[validate(schema(...))]`, are attached to the `prefix`.
+#[cfg(feature = "validator")]
+pub fn validator_errors(errors: &validator::ValidationErrors, ctx: &FormContext<'_>) -> Errors {
+    let mut result = Errors::new();
+    push_validator_errors(&mut result, errors, ctx.prefix, ctx.notation, &[]);
+    result
+}
+
+#[cfg(feature = "validator")]
+fn push_validator_errors(
+    result: &mut Errors,
+    errors: &validator::ValidationErrors,
+    parent: Option<&str>,
+    notation: crate::Notation,
+    names: &[(&str, &str)],
+) {
+    use validator::ValidationErrorsKind;
+
+    // The errors are in a map, sorted so they are always returned in the same order
+    let mut fields = errors.errors().iter().collect::<Vec<_>>();
+    fields.sort_by_key(|(field, _)| *field);
+
+    for (field, kind) in fields {
+        let name = match field.as_ref() {
+            "__all__" => None,
+            field => Some(form_name(names, field)),
+        };
+
+        let path = match (parent, name) {
+            (Some(parent), Some(name)) => Some(notation.join(parent, name)),
+            (None, name) => name.map(ToOwned::to_owned),
+            (parent, None) => parent.map(ToOwned::to_owned),
+        };
+
+        match kind {
+            ValidationErrorsKind::Field(errors) => {
+                for error in errors {
+                    let message = error.message.as_ref().unwrap_or(&error.code);
+                    let mut field_error = validation_error(message.as_ref());
+
+                    // The value that failed is not a constraint
+                    for (param, value) in error.params.iter().filter(|(p, _)| *p != "value") {
+                        field_error =
+                            field_error.with_constraint(param.as_ref(), constraint_value(value));
+                    }
+
+                    result.push(with_location(field_error, name, path.as_deref()));
+                }
+            }
+            ValidationErrorsKind::Struct(errors) => {
+                push_validator_errors(result, errors, path.as_deref(), notation, &[]);
+            }
+            ValidationErrorsKind::List(items) => {
+                for (index, errors) in items {
+                    let index = index.to_string();
+                    let item_path = match &path {
+                        Some(path) => notation.join(path, &index),
+                        None => index,
+                    };
+
+                    push_validator_errors(result, errors, Some(&item_path), notation, &[]);
+                }
+            }
+        }
+    }
+}
+
+/// Translates the report of [`garde::Validate`] into field errors.
+///
+/// The path of each error is joined to the `prefix` of the context with its notation,
+/// like `address[street]` or `items[0][name]`. The errors of the whole value are attached to the `prefix`.
+#[cfg(feature = "garde")]
+pub fn garde_errors(report: &garde::Report, ctx: &FormContext<'_>) -> Errors {
+    garde_errors_with_names(report, ctx, &[])
+}
+
+#[cfg(feature = "garde")]
+fn garde_errors_with_names(
+    report: &garde::Report,
+    ctx: &FormContext<'_>,
+    names: &[(&str, &str)],
+) -> Errors {
+    let mut result = Errors::new();
+
+    for (path, error) in report.iter() {
+        let mut name = None;
+        let mut full_path = ctx.prefix.map(ToOwned::to_owned);
+        let path = path.to_string();
+
+        for (position, component) in garde_path_components(&path).enumerate() {
+            let component = match position {
+                0 => form_name(names, component),
+                _ => component,
+            };
+
+            name = Some(component);
+            full_path = Some(match full_path {
+                Some(parent) => ctx.notation.join(&parent, component),
+                None => component.to_owned(),
+            });
+        }
+
+        let field_error = validation_error(error.message());
+        result.push(with_location(field_error, name, full_path.as_deref()));
+    }
+
+    result
+}
+
+/// Splits the `Display` of a garde path, like `items[0].name`, into its keys and indices.
+#[cfg(feature = "garde")]
+fn garde_path_components(path: &str) -> impl Iterator<Item = &str> {
+    path.split(['.', '[', ']'])
+        .filter(|component| !component.is_empty())
+}
+
+/// Returns the form name of a field from the `(field, form name)` pairs of the derive.
+#[cfg(any(feature = "validator", feature = "garde"))]
+fn form_name<'a>(names: &[(&'a str, &'a str)], field: &'a str) -> &'a str {
+    names
+        .iter()
+        .find(|(name, _)| *name == field)
+        .map_or(field, |(_, form_name)| form_name)
+}
+
+#[cfg(any(feature = "validator", feature = "garde"))]
+fn with_location(mut error: FieldError, name: Option<&str>, path: Option<&str>) -> FieldError {
+    if let Some(name) = name {
+        error = error.with_name(name);
+    }
+
+    if let Some(path) = path {
+        error = error.with_path(path);
+    }
+
+    error
+}
+
+/// Runs the validations of `validator` or `garde` from the derive, if the type implements them.
+///
+/// Each trait is implemented for a different number of references, so the method call
+/// picks the first the type implements: `validator`, `garde` or none.
+/// The method is resolved where the derive expands, for a generic type the `Validate` impl
+/// is only found if it does not depend on the type parameters, otherwise the type is not validated.
+#[doc(hidden)]
+pub mod __private {
+    use crate::{Error, FormContext};
+
+    pub struct External<'a, T>(pub &'a T);
+
+    pub trait ViaValidator {
+        fn __multer_validate(
+            &self,
+            ctx: &FormContext<'_>,
+            names: &[(&str, &str)],
+        ) -> Result<(), Error>;
+    }
+
+    #[cfg(feature = "validator")]
+    impl<T: validator::Validate> ViaValidator for &&External<'_, T> {
+        fn __multer_validate(
+            &self,
+            ctx: &FormContext<'_>,
+            names: &[(&str, &str)],
+        ) -> Result<(), Error> {
+            self.0.validate().map_err(|errors| {
+                let mut result = crate::Errors::new();
+                super::push_validator_errors(&mut result, &errors, ctx.prefix, ctx.notation, names);
+                result.into()
+            })
+        }
+    }
+
+    pub trait ViaGarde {
+        fn __multer_validate(
+            &self,
+            ctx: &FormContext<'_>,
+            names: &[(&str, &str)],
+        ) -> Result<(), Error>;
+    }
+
+    #[cfg(feature = "garde")]
+    impl<T> ViaGarde for &External<'_, T>
+    where
+        T: garde::Validate,
+        T::Context: Default,
+    {
+        fn __multer_validate(
+            &self,
+            ctx: &FormContext<'_>,
+            names: &[(&str, &str)],
+        ) -> Result<(), Error> {
+            self.0
+                .validate()
+                .map_err(|report| super::garde_errors_with_names(&report, ctx, names).into())
+        }
+    }
+
+    pub trait NoValidation {
+        fn __multer_validate(
+            &self,
+            ctx: &FormContext<'_>,
+            names: &[(&str, &str)],
+        ) -> Result<(), Error>;
+    }
+
+    impl<T> NoValidation for External<'_, T> {
+        fn __multer_validate(&self, _: &FormContext<'_>, _: &[(&str, &str)]) -> Result<(), Error> {
+            Ok(())
+        }
+    }
+}
+
+/// A value which length can be validated with `#[multer(validate(length(...)))]`.
+pub trait Length {
+    /// Returns the length of the value.
+    fn length(&self) -> usize;
+}
+
+impl Length for str {
+    /// Returns the number of characters of the string.
+    fn length(&self) -> usize {
+        self.chars().count()
+    }
+}
+
+impl Length for String {
+    fn length(&self) -> usize {
+        self.as_str().length()
+    }
+}
+
+impl<T> Length for [T] {
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<T> Length for Vec<T> {
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+impl Length for FormFile {
+    /// Returns the size of the file in bytes.
+    fn length(&self) -> usize {
+        self.size() as usize
+    }
+}
+
+/// Checks the length of the value is between `min` and `max`.
+pub fn length<T: Length + ?Sized>(
+    value: &T,
+    min: Option<usize>,
+    max: Option<usize>,
+) -> Result<(), FieldError> {
+    let len = value.length();
+    let too_short = min.is_some_and(|min| len < min);
+    let too_long = max.is_some_and(|max| len > max);
+
+    if !too_short && !too_long {
+        return Ok(());
+    }
+
+    let message = match (min, max) {
+        (Some(min), Some(max)) => format!("length must be between {min} and {max}"),
+        (Some(min), None) => format!("length must be at least {min}"),
+        (None, Some(max)) => format!("length must be at most {max}"),
+        (None, None) => unreachable!(),
+    };
+
+    Err(with_bounds(validation_error(message), min, max))
+}
+
+/// Checks the value is between `min` and `max`.
+pub fn range<T: PartialOrd + Display + Into<ConstraintValue>>(
+    value: &T,
+    min: Option<T>,
+    max: Option<T>,
+) -> Result<(), FieldError> {
+    let too_small = min.as_ref().is_some_and(|min| value < min);
+    let too_large = max.as_ref().is_some_and(|max| value > max);
+
+    if !too_small && !too_large {
+        return Ok(());
+    }
+
+    let message = match (&min, &max) {
+        (Some(min), Some(max)) => format!("must be between {min} and {max}"),
+        (Some(min), None) => format!("must be at least {min}"),
+        (None, Some(max)) => format!("must be at most {max}"),
+        (None, None) => unreachable!(),
+    };
+
+    Err(with_bounds(validation_error(message), min, max))
+}
+
+/// Checks the value matched a pattern.
+pub fn pattern(is_match: bool) -> Result<(), FieldError> {
+    match is_match {
+        true => Ok(()),
+        false => Err(validation_error("does not match the expected pattern")),
+    }
+}
+
+/// Checks the value is an email address like `user@example.com`.
+pub fn email<T: AsRef<str> + ?Sized>(value: &T) -> Result<(), FieldError> {
+    let value = value.as_ref();
+    let is_email = match value.split_once('@') {
+        Some((user, domain)) => {
+            !user.is_empty()
+                && !domain.contains('@')
+                && domain.contains('.')
+                && !domain.starts_with('.')
+                && !domain.ends_with('.')
+                && !value.contains(char::is_whitespace)
+        }
+        None => false,
+    };
+
+    match is_email {
+        true => Ok(()),
+        false => {
+            Err(validation_error("must be an email address").with_constraint("format", "email"))
+        }
+    }
+}
+
+/// Checks the value is an absolute url like `https://example.com`.
+pub fn url<T: AsRef<str> + ?Sized>(value: &T) -> Result<(), FieldError> {
+    let is_url = value
+        .as_ref()
+        .parse::<http::Uri>()
+        .is_ok_and(|uri| uri.scheme().is_some() && uri.authority().is_some());
+
+    match is_url {
+        true => Ok(()),
+        false => Err(validation_error("must be an url").with_constraint("format", "url")),
+    }
+}
+
+#[cfg(feature = "validator")]
+fn constraint_value(value: &serde_json::Value) -> ConstraintValue {
+    match value {
+        serde_json::Value::Bool(value) => ConstraintValue::Bool(*value),
+        serde_json::Value::Number(number) => match (number.as_u64(), number.as_i64()) {
+            (Some(value), _) => ConstraintValue::UInt(value),
+            (None, Some(value)) => ConstraintValue::Int(value),
+            (None, None) => ConstraintValue::Float(number.as_f64().unwrap_or(f64::NAN)),
+        },
+        serde_json::Value::String(value) => ConstraintValue::Text(value.clone()),
+        value => ConstraintValue::Text(value.to_string()),
+    }
+}
+
+fn validation_error(message: impl Into<String>) -> FieldError {
+    FieldError::new(FieldErrorKind::Validation).with_source(message.into())
+}
+
+fn with_bounds<T: Into<ConstraintValue>>(
+    mut error: FieldError,
+    min: Option<T>,
+    max: Option<T>,
+) -> FieldError {
+    if let Some(min) = min {
+        error = error.with_constraint("min", min);
+    }
+
+    if let Some(max) = max {
+        error = error.with_constraint("max", max);
+    }
+
+    error
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn length_test() {
+        assert!(length("hello", Some(1), Some(5)).is_ok());
+        assert!(length("héllo", None, Some(5)).is_ok());
+        assert!(length("", Some(1), None).is_err());
+        assert!(length(&vec![1, 2, 3], None, Some(2)).is_err());
+
+        let error = length("hello world", Some(1), Some(5)).unwrap_err();
+        assert_eq!(error.kind(), FieldErrorKind::Validation);
+        assert_eq!(
+            error.constraints().collect::<Vec<_>>(),
+            [
+                ("min", &ConstraintValue::UInt(1)),
+                ("max", &ConstraintValue::UInt(5))
+            ]
+        );
+    }
+
+    #[test]
+    fn range_test() {
+        assert!(range(&18, Some(18), None).is_ok());
+        assert!(range(&17, Some(18), None).is_err());
+        assert!(range(&0.5, Some(0.0), Some(1.0)).is_ok());
+        assert!(range(&-2, Some(-1), Some(1)).is_err());
+
+        let error = range(&-2, Some(-1), Some(1)).unwrap_err();
+        assert_eq!(
+            error.constraints().collect::<Vec<_>>(),
+            [
+                ("min", &ConstraintValue::Int(-1)),
+                ("max", &ConstraintValue::Int(1))
+            ]
+        );
+    }
+
+    #[test]
+    fn email_test() {
+        assert!(email("user@example.com").is_ok());
+        assert!(email("user@example").is_err());
+        assert!(email("@example.com").is_err());
+        assert!(email("user@@example.com").is_err());
+        assert!(email("us er@example.com").is_err());
+    }
+
+    #[test]
+    fn errors_test() {
+        let errors = errors([("name", "is required"), ("age", "must be at least 18")]);
+
+        assert_eq!(errors.len(), 2);
+        assert_eq!(
+            errors.to_string(),
+            "`name` form field is invalid: is required\n`age` form field is invalid: must be at least 18"
+        );
+    }
+
+    #[test]
+    fn url_test() {
+        assert!(url("https://example.com/path?query=1").is_ok());
+        assert!(url("example.com").is_err());
+        assert!(url("/path").is_err());
+    }
+}
@@ -0,0 +1,148 @@
+use crate::common::build_form;
+use multer_derive::{
+    helpers::MultipartFormBuilder, Error, FieldErrorKind, FormContext, FromMultipart, MultipartForm,
+};
+
+#[derive(Debug, FromMultipart)]
+#[multer(collect_errors)]
+struct Address {
+    street: String,
+    zip: u32,
+}
+
+#[derive(Debug, FromMultipart)]
+#[multer(collect_errors)]
+struct Person {
+    name: String,
+    age: u8,
+
+    #[multer(nested)]
+    address: Address,
+
+    #[multer(default)]
+    nickname: String,
+}
+
+#[derive(Debug, FromMultipart)]
+#[multer(tag = "kind", rename_all = "lowercase", collect_errors)]
+enum Source {
+    Url { url: String, title: String },
+}
+
+#[tokio::test]
+async fn collect_errors_ok_test() {
+    let form = build_form(
+        MultipartFormBuilder::new()
+            .text("name", "John")
+            .text("age", "30")
+            .text("address[street]", "Main St")
+            .text("address[zip]", "12345"),
+    )
+    .await;
+
+    let person = Person::from_multipart(&form, Default::default()).unwrap();
+
+    assert_eq!(person.name, "John");
+    assert_eq!(person.age, 30);
+    assert_eq!(person.address.street, "Main St");
+    assert_eq!(person.address.zip, 12345);
+    assert_eq!(person.nickname, "");
+}
+
+#[tokio::test]
+async fn collect_errors_all_fields_test() {
+    let form = build_form(
+        MultipartFormBuilder::new()
+            .text("age", "old")
+            .text("address[zip]", "abc"),
+    )
+    .await;
+
+    let err = Person::from_multipart(&form, Default::default())
+        .err()
+        .unwrap();
+    let errors = err.as_errors().unwrap();
+
+    assert_eq!(errors.len(), 4);
+
+    let by_path = errors.by_path();
+    assert_eq!(
+        by_path.keys().copied().collect::<Vec<_>>(),
+        ["address[street]", "address[zip]", "age", "name"]
+    );
+
+    assert_eq!(by_path["name"][0].kind(), FieldErrorKind::Missing);
+    assert_eq!(by_path["age"][0].kind(), FieldErrorKind::Parse);
+    assert_eq!(
+        by_path["address[street]"][0].kind(),
+        FieldErrorKind::Missing
+    );
+    assert_eq!(by_path["address[zip]"][0].kind(), FieldErrorKind::Parse);
+    assert_eq!(errors.get("address[zip]").count(), 1);
+    assert_eq!(errors.get("nickname").count(), 0);
+}
+
+#[tokio::test]
+async fn collect_errors_tagged_enum_test() {
+    let form = build_form(MultipartFormBuilder::new().text("kind", "url")).await;
+
+    let err = Source::from_multipart(&form, Default::default())
+        .err()
+        .unwrap();
+    let errors = err.as_errors().unwrap();
+
+    assert_eq!(
+        errors.to_string(),
+        "`url` form field was not found\n`title` form field was not found"
+    );
+}
+
+#[tokio::test]
+async fn collect_errors_tagged_enum_ok_test() {
+    let form = build_form(
+        MultipartFormBuilder::new()
+            .text("kind", "url")
+            .text("url", "https://example.com")
+            .text("title", "Example"),
+    )
+    .await;
+
+    let Source::Url { url, title } = Source::from_multipart(&form, Default::default()).unwrap();
+
+    assert_eq!(url, "https://example.com");
+    assert_eq!(title, "Example");
+}
+
+#[allow(dead_code)]
+#[derive(Debug, FromMultipart)]
+#[multer(collect_errors)]
+struct Post {
+    title: String,
+    #[multer(with = "tags_from_multipart")]
+    tags: Vec<String>,
+}
+
+fn tags_from_multipart(_: &MultipartForm, _: FormContext<'_>) -> Result<Vec<String>, Error> {
+    Err(Error::new("nope"))
+}
+
+#[tokio::test]
+async fn collect_errors_with_test() {
+    let form = build_form(MultipartFormBuilder::new().text("tags", "rust")).await;
+
+    let err = Post::from_multipart(&form, Default::default())
+        .err()
+        .unwrap();
+    let by_path = err.as_errors().unwrap().by_path();
+
+    assert_eq!(
+        by_path.keys().copied().collect::<Vec<_>>(),
+        ["tags", "title"]
+    );
+
+    let tags_error = by_path["tags"][0];
+    assert_eq!(tags_error.kind(), FieldErrorKind::Parse);
+    assert_eq!(tags_error.name(), Some("tags"));
+    assert_eq!(tags_error.index(), Some(0));
+    assert_eq!(tags_error.to_string(), "`tags` form field is invalid: nope");
+}
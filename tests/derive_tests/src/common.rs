@@ -0,0 +1,8 @@
+use multer_derive::{helpers::MultipartFormBuilder, multer::Multipart, MultipartForm};
+
+/// Builds the form and reads all its fields.
+pub async fn build_form(builder: &mut MultipartFormBuilder) -> MultipartForm {
+    let form_data = builder.build("my_boundary");
+    let multipart = Multipart::with_reader(form_data.as_bytes(), "my_boundary");
+    MultipartForm::with_multipart(multipart).await.unwrap()
+}
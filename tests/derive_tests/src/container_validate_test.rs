@@ -0,0 +1,123 @@
+use crate::common::build_form;
+use multer_derive::{
+    helpers::MultipartFormBuilder,
+    validate::{self, AsyncValidate},
+    Error, FieldError, FieldErrorKind, FromMultipart, MultipartForm,
+};
+
+#[derive(Debug, FromMultipart)]
+#[multer(validate = "check_passwords")]
+struct Signup {
+    username: String,
+    password: String,
+    password_confirm: String,
+}
+
+fn check_passwords(signup: &Signup) -> Result<(), FieldError> {
+    match signup.password == signup.password_confirm {
+        true => Ok(()),
+        false => Err(validate::error(
+            "password_confirm",
+            "must match the password",
+        )),
+    }
+}
+
+#[allow(dead_code)]
+#[derive(Debug, FromMultipart)]
+struct Account {
+    #[multer(nested)]
+    signup: Signup,
+}
+
+struct Store {
+    usernames: Vec<String>,
+}
+
+impl AsyncValidate<Store> for Signup {
+    async fn validate(&self, store: &Store) -> Result<(), Error> {
+        match store.usernames.contains(&self.username) {
+            true => Err(validate::error("username", "is already taken").into()),
+            false => Ok(()),
+        }
+    }
+}
+
+async fn signup_form(username: &str, confirm: &str) -> MultipartForm {
+    build_form(
+        MultipartFormBuilder::new()
+            .text("username", username)
+            .text("password", "secret")
+            .text("password_confirm", confirm),
+    )
+    .await
+}
+
+#[tokio::test]
+async fn container_validate_ok_test() {
+    let form = signup_form("john", "secret").await;
+    let signup = Signup::from_multipart(&form, Default::default()).unwrap();
+
+    assert_eq!(signup.username, "john");
+    assert_eq!(signup.password, "secret");
+    assert_eq!(signup.password_confirm, "secret");
+}
+
+#[tokio::test]
+async fn container_validate_error_test() {
+    let form = signup_form("john", "other").await;
+    let err = Signup::from_multipart(&form, Default::default())
+        .err()
+        .unwrap();
+    let field_error = err.as_field_error().unwrap();
+
+    assert_eq!(field_error.kind(), FieldErrorKind::Validation);
+    assert_eq!(field_error.path(), Some("password_confirm"));
+    assert_eq!(
+        err.to_string(),
+        "`password_confirm` form field is invalid: must match the password"
+    );
+}
+
+#[tokio::test]
+async fn container_validate_nested_test() {
+    let form = build_form(
+        MultipartFormBuilder::new()
+            .text("signup[username]", "john")
+            .text("signup[password]", "secret")
+            .text("signup[password_confirm]", "other"),
+    )
+    .await;
+
+    let err = Account::from_multipart(&form, Default::default())
+        .err()
+        .unwrap();
+    let field_error = err.as_field_error().unwrap();
+
+    assert_eq!(field_error.name(), Some("password_confirm"));
+    assert_eq!(field_error.path(), Some("signup[password_confirm]"));
+}
+
+#[tokio::test]
+async fn async_validate_test() {
+    let store = Store {
+        usernames: vec!["admin".to_owned()],
+    };
+
+    let form = signup_form("john", "secret").await;
+    let signup: Signup = validate::parse_and_validate(&form, Default::default(), &store)
+        .await
+        .unwrap();
+    assert_eq!(signup.username, "john");
+
+    let form = signup_form("admin", "secret").await;
+    let err = validate::parse_and_validate::<Signup, _>(&form, Default::default(), &store)
+        .await
+        .err()
+        .unwrap();
+
+    assert_eq!(
+        err.to_string(),
+        "`username` form field is invalid: is already taken"
+    );
+}
@@ -0,0 +1,99 @@
+use crate::common::build_form;
+use multer_derive::{helpers::MultipartFormBuilder, FieldErrorKind, FormFile, FromMultipart};
+
+#[allow(dead_code)]
+#[derive(Debug, FromMultipart)]
+struct Address {
+    street: String,
+    zip: u32,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, FromMultipart)]
+struct Person {
+    name: String,
+    #[multer(nested)]
+    address: Address,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, FromMultipart)]
+struct Upload {
+    file: FormFile,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, FromMultipart)]
+struct Point(i32, i32);
+
+#[tokio::test]
+async fn missing_nested_field_error_test() {
+    let form = build_form(
+        MultipartFormBuilder::new()
+            .text("name", "John")
+            .text("address[street]", "Main St"),
+    )
+    .await;
+
+    let err = Person::from_multipart(&form, Default::default())
+        .err()
+        .unwrap();
+    let field_error = err.as_field_error().unwrap();
+
+    assert_eq!(field_error.kind(), FieldErrorKind::Missing);
+    assert_eq!(field_error.name(), Some("zip"));
+    assert_eq!(field_error.path(), Some("address[zip]"));
+    assert_eq!(field_error.index(), None);
+    assert_eq!(err.to_string(), "`address[zip]` form field was not found");
+}
+
+#[tokio::test]
+async fn invalid_nested_field_error_test() {
+    let form = build_form(
+        MultipartFormBuilder::new()
+            .text("name", "John")
+            .text("address[street]", "Main St")
+            .text("address[zip]", "abc"),
+    )
+    .await;
+
+    let err = Person::from_multipart(&form, Default::default())
+        .err()
+        .unwrap();
+    let field_error = err.as_field_error().unwrap();
+
+    assert_eq!(field_error.kind(), FieldErrorKind::Parse);
+    assert_eq!(field_error.name(), Some("zip"));
+    assert_eq!(field_error.path(), Some("address[zip]"));
+    assert_eq!(field_error.index(), Some(2));
+    assert!(std::error::Error::source(field_error).is_some());
+}
+
+#[tokio::test]
+async fn not_a_file_error_test() {
+    let form = build_form(MultipartFormBuilder::new().text("file", "hello")).await;
+
+    let err = Upload::from_multipart(&form, Default::default())
+        .err()
+        .unwrap();
+    let field_error = err.as_field_error().unwrap();
+
+    assert_eq!(field_error.kind(), FieldErrorKind::NotAFile);
+    assert_eq!(field_error.path(), Some("file"));
+    assert_eq!(field_error.index(), Some(0));
+    assert_eq!(err.to_string(), "`file` form field is not a file");
+}
+
+#[tokio::test]
+async fn positional_field_error_test() {
+    let form = build_form(MultipartFormBuilder::new().text("x", "1")).await;
+
+    let err = Point::from_multipart(&form, Default::default())
+        .err()
+        .unwrap();
+    let field_error = err.as_field_error().unwrap();
+
+    assert_eq!(field_error.kind(), FieldErrorKind::Missing);
+    assert_eq!(field_error.name(), Some("1"));
+    assert_eq!(field_error.index(), Some(1));
+}
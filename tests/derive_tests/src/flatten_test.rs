@@ -0,0 +1,80 @@
+use crate::common::build_form;
+use multer_derive::{helpers::MultipartFormBuilder, FromMultipart};
+
+#[derive(Debug, PartialEq, FromMultipart)]
+struct Pagination {
+    page: u32,
+    per_page: u32,
+}
+
+#[derive(Debug, PartialEq, FromMultipart)]
+struct AuditInfo {
+    #[multer(rename = "audit_user")]
+    user: String,
+    #[multer(default)]
+    reason: Option<String>,
+}
+
+#[derive(Debug, FromMultipart)]
+struct Search {
+    query: String,
+
+    #[multer(flatten)]
+    pagination: Pagination,
+
+    #[multer(flatten)]
+    audit: AuditInfo,
+}
+
+#[derive(Debug, FromMultipart)]
+struct Request {
+    #[multer(nested)]
+    search: Search,
+}
+
+#[tokio::test]
+async fn flatten_test() {
+    let form = build_form(
+        MultipartFormBuilder::new()
+            .text("query", "rust")
+            .text("page", "2")
+            .text("per_page", "50")
+            .text("audit_user", "admin"),
+    )
+    .await;
+
+    let search = Search::from_multipart(&form, Default::default()).unwrap();
+    assert_eq!(search.query, "rust");
+    assert_eq!(
+        search.pagination,
+        Pagination {
+            page: 2,
+            per_page: 50
+        }
+    );
+    assert_eq!(
+        search.audit,
+        AuditInfo {
+            user: "admin".to_owned(),
+            reason: None
+        }
+    );
+}
+
+#[tokio::test]
+async fn flatten_nested_test() {
+    let form = build_form(
+        MultipartFormBuilder::new()
+            .text("search[query]", "rust")
+            .text("search[page]", "2")
+            .text("search[per_page]", "50")
+            .text("search[audit_user]", "admin")
+            .text("search[reason]", "testing"),
+    )
+    .await;
+
+    let request = Request::from_multipart(&form, Default::default()).unwrap();
+    assert_eq!(request.search.query, "rust");
+    assert_eq!(request.search.pagination.page, 2);
+    assert_eq!(request.search.audit.reason.as_deref(), Some("testing"));
+}
@@ -0,0 +1,137 @@
+use multer_derive::{
+    multer::{Field, Multipart},
+    Error, FieldErrorKind, FormFile, FromMultipart, FromMultipartAsync, MultipartForm,
+};
+
+const FORM_DATA :&str = "--boundary\r\nContent-Disposition: form-data; name=\"files\"; filename=\"filename1\"\r\nContent-Type: application/octet-stream\r\n\r\n[contents of file1]\r\n--boundary\r\nContent-Disposition: form-data; name=\"photo\"; filename=\"filename2\"\r\nContent-Type: application/octet-stream\r\n\r\n[contents of file2]\r\n--boundary\r\nContent-Disposition: form-data; name=\"files\"; filename=\"filename3\"\r\nContent-Type: application/octet-stream\r\n\r\n[contents of file3]\r\n--boundary\r\nContent-Disposition: form-data; name=\"userName\"\r\n\r\njohn\r\n--boundary--";
+
+#[derive(FromMultipartAsync)]
+#[multer(rename_all = "camelCase")]
+struct Upload {
+    #[multer(validate(length(min = 3)))]
+    user_name: String,
+
+    #[multer(with = "count_bytes", rename = "photo")]
+    photo_size: usize,
+
+    files: Vec<FormFile>,
+    description: Option<String>,
+
+    #[multer(default)]
+    tags: String,
+}
+
+async fn count_bytes(mut field: Field<'_>) -> Result<usize, Error> {
+    let mut size = 0;
+
+    while let Some(chunk) = field.chunk().await? {
+        size += chunk.len();
+    }
+
+    Ok(size)
+}
+
+#[derive(Debug, FromMultipart, FromMultipartAsync)]
+struct Person {
+    name: String,
+    age: u8,
+    email: String,
+}
+
+fn multipart(form_data: &str) -> Multipart<'_> {
+    Multipart::with_reader(form_data.as_bytes(), "boundary")
+}
+
+#[tokio::test]
+async fn from_multipart_async_test() {
+    let upload = Upload::from_multipart_async(multipart(FORM_DATA))
+        .await
+        .unwrap();
+
+    assert_eq!(upload.user_name, "john");
+    assert_eq!(upload.photo_size, "[contents of file2]".len());
+    assert_eq!(upload.files.len(), 2);
+    assert_eq!(upload.files[0].file_name(), "filename1");
+    assert_eq!(upload.files[1].bytes().as_ref(), b"[contents of file3]");
+    assert_eq!(upload.description, None);
+    assert_eq!(upload.tags, "");
+}
+
+#[tokio::test]
+async fn from_multipart_async_any_order_test() {
+    let form_data = "--boundary\r\nContent-Disposition: form-data; name=\"email\"\r\n\r\njohn@example.com\r\n--boundary\r\nContent-Disposition: form-data; name=\"age\"\r\n\r\n20\r\n--boundary\r\nContent-Disposition: form-data; name=\"name\"\r\n\r\njohn\r\n--boundary--";
+    let person = Person::from_multipart_async(multipart(form_data))
+        .await
+        .unwrap();
+
+    assert_eq!(person.name, "john");
+    assert_eq!(person.age, 20);
+    assert_eq!(person.email, "john@example.com");
+}
+
+#[tokio::test]
+async fn from_multipart_async_validate_test() {
+    let form_data = FORM_DATA.replace("john", "jo");
+    let error = Upload::from_multipart_async(multipart(&form_data))
+        .await
+        .err()
+        .unwrap();
+
+    let error = error.as_field_error().unwrap();
+    assert_eq!(error.kind(), FieldErrorKind::Validation);
+    assert_eq!(error.path(), Some("userName"));
+}
+
+#[tokio::test]
+async fn from_multipart_async_missing_test() {
+    let form_data =
+        "--boundary\r\nContent-Disposition: form-data; name=\"age\"\r\n\r\n20\r\n--boundary--";
+    let error = Person::from_multipart_async(multipart(form_data))
+        .await
+        .unwrap_err();
+
+    let errors = error.as_errors().unwrap();
+    let missing = errors
+        .iter()
+        .map(|e| (e.kind(), e.path()))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        missing,
+        [
+            (FieldErrorKind::Missing, Some("name")),
+            (FieldErrorKind::Missing, Some("email"))
+        ]
+    );
+}
+
+#[tokio::test]
+async fn from_multipart_async_parse_error_test() {
+    let form_data =
+        "--boundary\r\nContent-Disposition: form-data; name=\"age\"\r\n\r\ntwenty\r\n--boundary--";
+    let error = Person::from_multipart_async(multipart(form_data))
+        .await
+        .unwrap_err();
+
+    let error = error.as_field_error().unwrap();
+    assert_eq!(error.kind(), FieldErrorKind::Parse);
+    assert_eq!(error.path(), Some("age"));
+    assert_eq!(error.index(), Some(0));
+}
+
+#[tokio::test]
+async fn from_multipart_async_duplicate_test() {
+    let form_data = "--boundary\r\nContent-Disposition: form-data; name=\"name\"\r\n\r\njohn\r\n--boundary\r\nContent-Disposition: form-data; name=\"name\"\r\n\r\njane\r\n--boundary\r\nContent-Disposition: form-data; name=\"age\"\r\n\r\n30\r\n--boundary\r\nContent-Disposition: form-data; name=\"email\"\r\n\r\njohn@example.com\r\n--boundary--";
+
+    // The first value is used, like `FromMultipart`
+    let person = Person::from_multipart_async(multipart(form_data))
+        .await
+        .unwrap();
+    assert_eq!(person.name, "john");
+
+    let form = MultipartForm::with_multipart(multipart(form_data))
+        .await
+        .unwrap();
+    let person = Person::from_multipart(&form, Default::default()).unwrap();
+    assert_eq!(person.name, "john");
+}
@@ -0,0 +1,58 @@
+use multer_derive::{
+    helpers::MultipartFormBuilder, multer::Multipart, FromMultipart, FromMultipartField,
+    MultipartForm,
+};
+
+#[derive(Debug, PartialEq, FromMultipartField)]
+enum Plan {
+    Free,
+    Pro,
+    #[multer(rename = "enterprise")]
+    Enterprise,
+}
+
+#[derive(FromMultipart)]
+struct Subscription {
+    email: String,
+    plan: Plan,
+}
+
+async fn get_form(plan: &str) -> MultipartForm {
+    let form_data = MultipartFormBuilder::new()
+        .text("email", "john@example.com")
+        .text("plan", plan)
+        .build("my_boundary");
+
+    let multipart = Multipart::with_reader(form_data.as_bytes(), "my_boundary");
+    MultipartForm::with_multipart(multipart).await.unwrap()
+}
+
+#[tokio::test]
+async fn enum_from_multipart_field_test() {
+    let form = get_form("Pro").await;
+    let result = Subscription::from_multipart(&form, Default::default()).unwrap();
+
+    assert_eq!(result.email, "john@example.com");
+    assert_eq!(result.plan, Plan::Pro);
+}
+
+#[tokio::test]
+async fn enum_from_multipart_field_rename_test() {
+    let form = get_form("enterprise").await;
+    let result = Subscription::from_multipart(&form, Default::default()).unwrap();
+
+    assert_eq!(result.plan, Plan::Enterprise);
+}
+
+#[tokio::test]
+async fn enum_from_multipart_field_unknown_variant_test() {
+    let form = get_form("Enterprise").await;
+    let err = Subscription::from_multipart(&form, Default::default())
+        .err()
+        .unwrap();
+
+    assert_eq!(
+        err.to_string(),
+        "`plan` form field is invalid: unknown variant `Enterprise`, expected one of `Free`, `Pro`, `enterprise`"
+    );
+}
@@ -0,0 +1,122 @@
+use crate::common::build_form;
+use garde::Validate;
+use multer_derive::{
+    helpers::MultipartFormBuilder, validate, FieldErrorKind, FormContext, FromMultipart, Notation,
+};
+
+#[derive(Debug, FromMultipart, Validate)]
+struct Address {
+    #[garde(length(min = 1))]
+    street: String,
+}
+
+#[derive(Debug, FromMultipart, Validate)]
+struct Item {
+    #[garde(length(max = 4))]
+    name: String,
+}
+
+#[derive(Debug, FromMultipart, Validate)]
+#[multer(notation = "dots")]
+struct Order {
+    #[multer(rename = "qty")]
+    #[garde(range(min = 1))]
+    quantity: u32,
+
+    #[multer(nested)]
+    #[garde(dive)]
+    address: Address,
+
+    #[multer(indexed)]
+    #[garde(dive)]
+    items: Vec<Item>,
+}
+
+#[tokio::test]
+async fn garde_ok_test() {
+    let form = build_form(
+        MultipartFormBuilder::new()
+            .text("qty", "2")
+            .text("address.street", "Main St")
+            .text("items.0.name", "pen"),
+    )
+    .await;
+
+    let order = Order::from_multipart(&form, Default::default()).unwrap();
+    assert_eq!(order.quantity, 2);
+    assert_eq!(order.items[0].name, "pen");
+}
+
+#[tokio::test]
+async fn garde_renamed_field_test() {
+    let form = build_form(
+        MultipartFormBuilder::new()
+            .text("qty", "0")
+            .text("address.street", "Main St"),
+    )
+    .await;
+
+    let err = Order::from_multipart(&form, Default::default())
+        .err()
+        .unwrap();
+    let errors = err.as_errors().unwrap();
+
+    assert_eq!(errors.len(), 1);
+    let error = errors.iter().next().unwrap();
+    assert_eq!(error.kind(), FieldErrorKind::Validation);
+    assert_eq!(error.name(), Some("qty"));
+    assert_eq!(error.path(), Some("qty"));
+}
+
+#[tokio::test]
+async fn garde_indexed_field_test() {
+    let form = build_form(
+        MultipartFormBuilder::new()
+            .text("qty", "1")
+            .text("address.street", "Main St")
+            .text("items.0.name", "pen")
+            .text("items.1.name", "pencil"),
+    )
+    .await;
+
+    let err = Order::from_multipart(&form, Default::default())
+        .err()
+        .unwrap();
+    let error = err.as_errors().unwrap().iter().next().unwrap();
+
+    assert_eq!(error.name(), Some("name"));
+    assert_eq!(error.path(), Some("items.1.name"));
+}
+
+#[test]
+fn garde_errors_test() {
+    let order = Order {
+        quantity: 1,
+        address: Address {
+            street: String::new(),
+        },
+        items: vec![
+            Item {
+                name: "pen".to_owned(),
+            },
+            Item {
+                name: "pencil".to_owned(),
+            },
+        ],
+    };
+
+    let ctx = FormContext {
+        prefix: Some("order"),
+        notation: Notation::Brackets,
+        ..Default::default()
+    };
+
+    let errors = validate::garde_errors(&order.validate().unwrap_err(), &ctx);
+    let by_path = errors.by_path();
+
+    assert_eq!(
+        by_path.keys().copied().collect::<Vec<_>>(),
+        ["order[address][street]", "order[items][1][name]"]
+    );
+    assert_eq!(by_path["order[address][street]"][0].name(), Some("street"));
+}
@@ -0,0 +1,118 @@
+use crate::common::build_form;
+use multer_derive::{helpers::MultipartFormBuilder, FromMultipart};
+
+#[derive(Debug, PartialEq, FromMultipart)]
+struct LineItem {
+    name: String,
+    quantity: u32,
+}
+
+#[derive(Debug, FromMultipart)]
+struct Order {
+    customer: String,
+
+    #[multer(indexed)]
+    items: Vec<LineItem>,
+
+    #[multer(indexed, default)]
+    tags: Vec<String>,
+}
+
+#[tokio::test]
+async fn indexed_structs_test() {
+    let form = build_form(
+        MultipartFormBuilder::new()
+            .text("customer", "John Smith")
+            .text("items[0][name]", "Apple")
+            .text("items[0][quantity]", "3")
+            .text("items[1][name]", "Orange")
+            .text("items[1][quantity]", "5")
+            .text("tags[0]", "fruit")
+            .text("tags[1]", "fresh"),
+    )
+    .await;
+
+    let order = Order::from_multipart(&form, Default::default()).unwrap();
+    assert_eq!(order.customer, "John Smith");
+    assert_eq!(
+        order.items,
+        vec![
+            LineItem {
+                name: "Apple".to_owned(),
+                quantity: 3
+            },
+            LineItem {
+                name: "Orange".to_owned(),
+                quantity: 5
+            }
+        ]
+    );
+    assert_eq!(order.tags, vec!["fruit", "fresh"]);
+}
+
+#[tokio::test]
+async fn indexed_missing_default_test() {
+    let form = build_form(
+        MultipartFormBuilder::new()
+            .text("customer", "John Smith")
+            .text("items[0][name]", "Apple")
+            .text("items[0][quantity]", "3"),
+    )
+    .await;
+
+    let order = Order::from_multipart(&form, Default::default()).unwrap();
+    assert_eq!(order.items.len(), 1);
+    assert!(order.tags.is_empty());
+}
+
+#[tokio::test]
+async fn indexed_gap_test() {
+    let form = build_form(
+        MultipartFormBuilder::new()
+            .text("customer", "John Smith")
+            .text("items[0][name]", "Apple")
+            .text("items[0][quantity]", "3")
+            .text("items[2][name]", "Orange")
+            .text("items[2][quantity]", "5"),
+    )
+    .await;
+
+    let err = Order::from_multipart(&form, Default::default()).unwrap_err();
+    assert_eq!(err.to_string(), "`items[1]` form field was not found");
+}
+
+#[tokio::test]
+async fn indexed_out_of_order_test() {
+    let form = build_form(
+        MultipartFormBuilder::new()
+            .text("customer", "John Smith")
+            .text("items[1][name]", "Orange")
+            .text("items[1][quantity]", "5")
+            .text("items[0][name]", "Apple")
+            .text("items[0][quantity]", "3"),
+    )
+    .await;
+
+    let err = Order::from_multipart(&form, Default::default()).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "`items` form field is invalid: indices are out of order, found `0` after `1`"
+    );
+}
+
+#[tokio::test]
+async fn indexed_leading_zeros_test() {
+    let form = build_form(
+        MultipartFormBuilder::new()
+            .text("customer", "John Smith")
+            .text("items[00][name]", "Apple")
+            .text("items[00][quantity]", "3"),
+    )
+    .await;
+
+    let err = Order::from_multipart(&form, Default::default()).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "`items` form field is invalid: invalid index `00`, indices must not have leading zeros"
+    );
+}
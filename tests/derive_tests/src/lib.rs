@@ -1,7 +1,25 @@
 #![cfg(test)]
+mod collect_errors_test;
+mod common;
+mod container_validate_test;
+mod field_error_test;
+mod flatten_test;
+mod from_multipart_async_test;
+mod from_multipart_field_test;
 mod from_multipart_test;
-mod multiple_files_test;
+mod garde_test;
 mod generic_test;
+mod indexed_test;
+mod limit_test;
+mod multer_default_test;
+mod multer_rename_all_test;
 mod multer_rename_test;
+mod multer_skip_test;
 mod multer_with_test;
-mod multiples_files_rename_test;
\ No newline at end of file
+mod multiple_files_test;
+mod multiples_files_rename_test;
+mod nested_test;
+mod tagged_enum_test;
+mod tuple_struct_test;
+mod validate_test;
+mod validator_test;
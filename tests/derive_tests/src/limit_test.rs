@@ -0,0 +1,147 @@
+use multer_derive::{
+    helpers::MultipartFormBuilder, multer::Multipart, ConstraintValue, FieldErrorKind, FormFile,
+    FromMultipart, MultipartForm,
+};
+
+#[derive(Debug, FromMultipart)]
+struct Address {
+    #[multer(limit = "8B")]
+    street: String,
+}
+
+#[derive(Debug, FromMultipart)]
+struct Profile {
+    #[multer(limit = "16B")]
+    bio: String,
+
+    #[multer(limit = "1KiB")]
+    avatar: FormFile,
+
+    #[multer(nested)]
+    address: Address,
+}
+
+fn profile_form(bio: &str, street: &str) -> String {
+    MultipartFormBuilder::new()
+        .text("bio", bio)
+        .raw_file(
+            "avatar",
+            "image",
+            "avatar.png",
+            multer_derive::mime::IMAGE_PNG,
+        )
+        .text("address[street]", street)
+        .build("my_boundary")
+}
+
+#[test]
+fn derived_constraints_test() {
+    let constraints = Profile::constraints();
+
+    assert_eq!(constraints.max_size(Some("bio"), false), Some(16));
+    assert_eq!(constraints.max_size(Some("avatar"), true), Some(1024));
+    assert_eq!(
+        constraints.max_size(Some("address[street]"), false),
+        Some(8)
+    );
+    assert_eq!(constraints.max_size(Some("street"), false), None);
+}
+
+#[tokio::test]
+async fn parse_with_limits_test() {
+    let form_data = profile_form("Hello", "Main St");
+    let multipart = Multipart::with_reader(form_data.as_bytes(), "my_boundary");
+    let profile = MultipartForm::parse::<Profile>(multipart).await.unwrap();
+
+    assert_eq!(profile.bio, "Hello");
+    assert_eq!(profile.avatar.file_name(), "avatar.png");
+    assert_eq!(profile.address.street, "Main St");
+}
+
+#[tokio::test]
+async fn parse_exceeds_limit_test() {
+    let form_data = profile_form("Hello", "Main Street");
+    let multipart = Multipart::with_reader(form_data.as_bytes(), "my_boundary");
+    let err = MultipartForm::parse::<Profile>(multipart)
+        .await
+        .err()
+        .unwrap();
+    let field_error = err.as_field_error().unwrap();
+
+    assert_eq!(field_error.kind(), FieldErrorKind::TooLarge);
+    assert_eq!(field_error.path(), Some("address[street]"));
+    assert_eq!(
+        field_error.constraints().collect::<Vec<_>>(),
+        [("max_size", &ConstraintValue::UInt(8))]
+    );
+}
+
+#[allow(dead_code)]
+#[derive(Debug, FromMultipart)]
+struct Item {
+    #[multer(limit = "4B")]
+    name: String,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, FromMultipart)]
+struct Order {
+    #[multer(indexed)]
+    items: Vec<Item>,
+
+    #[multer(nested)]
+    shipping: Profile,
+}
+
+#[test]
+fn derived_indexed_constraints_test() {
+    let constraints = Order::constraints();
+
+    assert_eq!(constraints.max_size(Some("items[0][name]"), false), Some(4));
+    assert_eq!(
+        constraints.max_size(Some("items[12][name]"), false),
+        Some(4)
+    );
+    assert_eq!(
+        constraints.max_size(Some("shipping[address][street]"), false),
+        Some(8)
+    );
+}
+
+#[allow(dead_code)]
+#[derive(Debug, FromMultipart)]
+#[multer(notation = "dots")]
+struct DotsOrder {
+    #[multer(indexed)]
+    items: Vec<Item>,
+}
+
+#[test]
+fn derived_indexed_dots_constraints_test() {
+    let constraints = DotsOrder::constraints();
+
+    assert_eq!(constraints.max_size(Some("items.0.name"), false), Some(4));
+    assert_eq!(constraints.max_size(Some("items.12.name"), false), Some(4));
+}
+
+#[tokio::test]
+async fn parse_exceeds_indexed_limit_test() {
+    let form_data = MultipartFormBuilder::new()
+        .text("items[0][name]", "pen")
+        .text("items[1][name]", "a long name")
+        .build("my_boundary");
+
+    let multipart = Multipart::with_reader(form_data.as_bytes(), "my_boundary");
+    let err = MultipartForm::parse::<Order>(multipart)
+        .await
+        .err()
+        .unwrap();
+    let field_error = err.as_field_error().unwrap();
+
+    assert_eq!(field_error.kind(), FieldErrorKind::TooLarge);
+    assert_eq!(field_error.path(), Some("items[1][name]"));
+    assert_eq!(
+        field_error.constraints().collect::<Vec<_>>(),
+        [("max_size", &ConstraintValue::UInt(4))]
+    );
+}
@@ -0,0 +1,79 @@
+use crate::common::build_form;
+use multer_derive::{helpers::MultipartFormBuilder, FromMultipart};
+
+#[derive(FromMultipart)]
+struct Settings {
+    name: String,
+
+    #[multer(default)]
+    page_size: u32,
+
+    #[multer(default = "default_theme")]
+    theme: String,
+}
+
+fn default_theme() -> String {
+    "dark".to_owned()
+}
+
+#[derive(FromMultipart)]
+#[multer(default)]
+struct Pagination {
+    page: u32,
+    per_page: u32,
+}
+
+impl Default for Pagination {
+    fn default() -> Self {
+        Pagination {
+            page: 1,
+            per_page: 20,
+        }
+    }
+}
+
+#[tokio::test]
+async fn field_default_missing_test() {
+    let form = build_form(MultipartFormBuilder::new().text("name", "settings")).await;
+    let settings = Settings::from_multipart(&form, Default::default()).unwrap();
+
+    assert_eq!(settings.name, "settings");
+    assert_eq!(settings.page_size, 0);
+    assert_eq!(settings.theme, "dark");
+}
+
+#[tokio::test]
+async fn field_default_present_test() {
+    let form = build_form(
+        MultipartFormBuilder::new()
+            .text("name", "settings")
+            .text("page_size", "50")
+            .text("theme", "light"),
+    )
+    .await;
+    let settings = Settings::from_multipart(&form, Default::default()).unwrap();
+
+    assert_eq!(settings.page_size, 50);
+    assert_eq!(settings.theme, "light");
+}
+
+#[tokio::test]
+async fn field_default_invalid_test() {
+    let form = build_form(
+        MultipartFormBuilder::new()
+            .text("name", "settings")
+            .text("page_size", "fifty"),
+    )
+    .await;
+
+    assert!(Settings::from_multipart(&form, Default::default()).is_err());
+}
+
+#[tokio::test]
+async fn container_default_test() {
+    let form = build_form(MultipartFormBuilder::new().text("per_page", "100")).await;
+    let pagination = Pagination::from_multipart(&form, Default::default()).unwrap();
+
+    assert_eq!(pagination.page, 1);
+    assert_eq!(pagination.per_page, 100);
+}
@@ -0,0 +1,87 @@
+use crate::common::build_form;
+use multer_derive::{helpers::MultipartFormBuilder, FromMultipart, FromMultipartField};
+
+#[derive(FromMultipart)]
+#[multer(rename_all = "camelCase")]
+struct CamelCase {
+    first_name: String,
+    #[multer(rename = "last-name")]
+    last_name: String,
+}
+
+#[derive(FromMultipart)]
+#[multer(rename_all = "SCREAMING_SNAKE_CASE")]
+struct ScreamingSnakeCase {
+    ssn: String,
+    zip_code: String,
+}
+
+#[derive(FromMultipart)]
+#[multer(rename_all = "kebab-case")]
+struct KebabCase {
+    first_name: String,
+    last_name: String,
+}
+
+#[derive(Debug, PartialEq, FromMultipartField)]
+#[multer(rename_all = "snake_case")]
+enum AccountType {
+    Personal,
+    SmallBusiness,
+}
+
+#[derive(FromMultipart)]
+#[multer(rename_all = "PascalCase")]
+struct PascalCase {
+    account_type: AccountType,
+}
+
+#[tokio::test]
+async fn rename_all_camel_case_test() {
+    let form = build_form(
+        MultipartFormBuilder::new()
+            .text("firstName", "John")
+            .text("last-name", "Smith"),
+    )
+    .await;
+
+    let result = CamelCase::from_multipart(&form, Default::default()).unwrap();
+    assert_eq!(result.first_name, "John");
+    assert_eq!(result.last_name, "Smith");
+}
+
+#[tokio::test]
+async fn rename_all_screaming_snake_case_test() {
+    let form = build_form(
+        MultipartFormBuilder::new()
+            .text("SSN", "123-45-6789")
+            .text("ZIP_CODE", "12345"),
+    )
+    .await;
+
+    let result = ScreamingSnakeCase::from_multipart(&form, Default::default()).unwrap();
+    assert_eq!(result.ssn, "123-45-6789");
+    assert_eq!(result.zip_code, "12345");
+}
+
+#[tokio::test]
+async fn rename_all_kebab_case_test() {
+    let form = build_form(
+        MultipartFormBuilder::new()
+            .text("first-name", "John")
+            .text("last-name", "Smith"),
+    )
+    .await;
+
+    let result = KebabCase::from_multipart(&form, Default::default()).unwrap();
+    assert_eq!(result.first_name, "John");
+    assert_eq!(result.last_name, "Smith");
+}
+
+#[tokio::test]
+async fn rename_all_variants_test() {
+    let form = build_form(MultipartFormBuilder::new().text("AccountType", "small_business")).await;
+
+    let result = PascalCase::from_multipart(&form, Default::default()).unwrap();
+    assert_eq!(result.account_type, AccountType::SmallBusiness);
+}
@@ -26,7 +26,7 @@ async fn multer_rename_from_multipart_test() {
     assert_eq!(person.name, "John Smith");
     assert_eq!(person.email, "john@example.com");
     assert_eq!(person.age, 25);
-    assert_eq!(person.is_married, true);
+    assert!(person.is_married);
 
     let str = String::from_utf8(person.image_file.bytes().to_vec()).unwrap();
     assert_eq!(str, "[Binary data]");
@@ -0,0 +1,58 @@
+use std::marker::PhantomData;
+
+use crate::common::build_form;
+use multer_derive::{helpers::MultipartFormBuilder, FromMultipart};
+
+struct NotFromMultipart;
+
+#[derive(FromMultipart)]
+struct Comment {
+    text: String,
+
+    #[multer(skip)]
+    likes: u32,
+
+    #[multer(skip, default = "received_at")]
+    received_at: u64,
+
+    #[multer(skip)]
+    _marker: PhantomData<NotFromMultipart>,
+}
+
+fn received_at() -> u64 {
+    1_000
+}
+
+#[derive(FromMultipart)]
+struct Pair(String, #[multer(skip)] u32, u8);
+
+#[tokio::test]
+async fn skip_test() {
+    let form = build_form(
+        MultipartFormBuilder::new()
+            .text("text", "Hello")
+            .text("likes", "100")
+            .text("received_at", "invalid"),
+    )
+    .await;
+
+    let comment = Comment::from_multipart(&form, Default::default()).unwrap();
+    assert_eq!(comment.text, "Hello");
+    assert_eq!(comment.likes, 0);
+    assert_eq!(comment.received_at, 1_000);
+}
+
+#[tokio::test]
+async fn skip_tuple_struct_test() {
+    let form = build_form(
+        MultipartFormBuilder::new()
+            .text("name", "John Smith")
+            .text("age", "25"),
+    )
+    .await;
+
+    let pair = Pair::from_multipart(&form, Default::default()).unwrap();
+    assert_eq!(pair.0, "John Smith");
+    assert_eq!(pair.1, 0);
+    assert_eq!(pair.2, 25);
+}
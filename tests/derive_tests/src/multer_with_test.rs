@@ -46,7 +46,7 @@ async fn multer_with_from_multipart_test() {
     assert_eq!(person.name, Name("John Smith".to_owned()));
     assert_eq!(person.email, "john@example.com");
     assert_eq!(person.age, 25);
-    assert_eq!(person.married, true);
+    assert!(person.married);
 
     let str = String::from_utf8(person.photo.bytes().to_vec()).unwrap();
     assert_eq!(str, "[Binary data]");
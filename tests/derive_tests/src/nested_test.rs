@@ -0,0 +1,152 @@
+use crate::common::build_form;
+use multer_derive::{helpers::MultipartFormBuilder, FromMultipart};
+
+#[derive(Debug, PartialEq, FromMultipart)]
+struct Address {
+    street: String,
+    city: String,
+}
+
+#[derive(Debug, FromMultipart)]
+struct Person {
+    name: String,
+
+    #[multer(nested)]
+    address: Address,
+
+    #[multer(prefix = "billing")]
+    billing_address: Address,
+}
+
+#[derive(Debug, FromMultipart)]
+#[multer(notation = "dots")]
+struct DottedPerson {
+    name: String,
+
+    #[multer(nested)]
+    address: Address,
+}
+
+#[derive(Debug, FromMultipart)]
+struct Company {
+    #[multer(nested)]
+    owner: Person,
+}
+
+#[derive(Debug, PartialEq, Default, FromMultipart)]
+struct Coordinates {
+    lat: f64,
+    lng: f64,
+}
+
+#[derive(Debug, FromMultipart)]
+struct Place {
+    name: String,
+
+    #[multer(nested, default)]
+    coordinates: Coordinates,
+}
+
+#[tokio::test]
+async fn nested_brackets_test() {
+    let form = build_form(
+        MultipartFormBuilder::new()
+            .text("name", "John Smith")
+            .text("address[street]", "Main St")
+            .text("address[city]", "Springfield")
+            .text("billing[street]", "Elm St")
+            .text("billing[city]", "Shelbyville"),
+    )
+    .await;
+
+    let person = Person::from_multipart(&form, Default::default()).unwrap();
+    assert_eq!(person.name, "John Smith");
+    assert_eq!(
+        person.address,
+        Address {
+            street: "Main St".to_owned(),
+            city: "Springfield".to_owned()
+        }
+    );
+    assert_eq!(
+        person.billing_address,
+        Address {
+            street: "Elm St".to_owned(),
+            city: "Shelbyville".to_owned()
+        }
+    );
+}
+
+#[tokio::test]
+async fn nested_dots_test() {
+    let form = build_form(
+        MultipartFormBuilder::new()
+            .text("name", "John Smith")
+            .text("address.street", "Main St")
+            .text("address.city", "Springfield"),
+    )
+    .await;
+
+    let person = DottedPerson::from_multipart(&form, Default::default()).unwrap();
+    assert_eq!(person.name, "John Smith");
+    assert_eq!(person.address.street, "Main St");
+    assert_eq!(person.address.city, "Springfield");
+}
+
+#[tokio::test]
+async fn nested_multiple_levels_test() {
+    let form = build_form(
+        MultipartFormBuilder::new()
+            .text("owner[name]", "John Smith")
+            .text("owner[address][street]", "Main St")
+            .text("owner[address][city]", "Springfield")
+            .text("owner[billing][street]", "Elm St")
+            .text("owner[billing][city]", "Shelbyville"),
+    )
+    .await;
+
+    let company = Company::from_multipart(&form, Default::default()).unwrap();
+    assert_eq!(company.owner.name, "John Smith");
+    assert_eq!(company.owner.address.city, "Springfield");
+    assert_eq!(company.owner.billing_address.city, "Shelbyville");
+}
+
+#[tokio::test]
+async fn nested_missing_field_test() {
+    let form = build_form(
+        MultipartFormBuilder::new()
+            .text("name", "John Smith")
+            .text("address[street]", "Main St"),
+    )
+    .await;
+
+    let err = DottedPerson::from_multipart(&form, Default::default()).unwrap_err();
+    assert_eq!(err.to_string(), "`address.street` form field was not found");
+
+    let err = Person::from_multipart(&form, Default::default()).unwrap_err();
+    assert_eq!(err.to_string(), "`address[city]` form field was not found");
+}
+
+#[tokio::test]
+async fn nested_default_test() {
+    let form = build_form(MultipartFormBuilder::new().text("name", "Home")).await;
+    let place = Place::from_multipart(&form, Default::default()).unwrap();
+    assert_eq!(place.name, "Home");
+    assert_eq!(place.coordinates, Coordinates::default());
+
+    let form = build_form(
+        MultipartFormBuilder::new()
+            .text("name", "Home")
+            .text("coordinates[lat]", "10.5")
+            .text("coordinates[lng]", "-3.25"),
+    )
+    .await;
+    let place = Place::from_multipart(&form, Default::default()).unwrap();
+    assert_eq!(
+        place.coordinates,
+        Coordinates {
+            lat: 10.5,
+            lng: -3.25
+        }
+    );
+}
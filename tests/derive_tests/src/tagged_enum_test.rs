@@ -0,0 +1,140 @@
+use multer_derive::{
+    helpers::MultipartFormBuilder, multer::Multipart, FormFile, FromMultipart, MultipartForm,
+};
+
+#[derive(Debug, FromMultipart)]
+#[multer(tag = "kind")]
+enum Upload {
+    #[multer(rename = "url")]
+    Url { url: String },
+
+    #[multer(rename = "file")]
+    File {
+        file: Box<FormFile>,
+        #[multer(rename = "description")]
+        desc: Option<String>,
+    },
+
+    #[multer(rename = "none")]
+    Empty,
+}
+
+#[derive(Debug, FromMultipart)]
+#[multer(tag = "kind", rename_all = "camelCase")]
+enum Link {
+    ImageUrl { image_url: String },
+    PageUrl { page_url: String },
+}
+
+#[tokio::test]
+async fn tagged_enum_named_fields_test() {
+    let form_data = MultipartFormBuilder::new()
+        .text("kind", "url")
+        .text("url", "https://example.com/image.png")
+        .build("my_boundary");
+
+    let multipart = Multipart::with_reader(form_data.as_bytes(), "my_boundary");
+    let form = MultipartForm::with_multipart(multipart).await.unwrap();
+    let result = Upload::from_multipart(&form, Default::default()).unwrap();
+
+    match result {
+        Upload::Url { url } => assert_eq!(url, "https://example.com/image.png"),
+        other => panic!("unexpected variant: {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn tagged_enum_only_parses_selected_variant_test() {
+    let form_data = MultipartFormBuilder::new()
+        .text("kind", "file")
+        .text("description", "an example file")
+        .raw_file(
+            "file",
+            b"[contents of file]",
+            "file.txt",
+            multer_derive::mime::TEXT_PLAIN,
+        )
+        .build("my_boundary");
+
+    let multipart = Multipart::with_reader(form_data.as_bytes(), "my_boundary");
+    let form = MultipartForm::with_multipart(multipart).await.unwrap();
+    let result = Upload::from_multipart(&form, Default::default()).unwrap();
+
+    match result {
+        Upload::File { file, desc } => {
+            assert_eq!(file.file_name(), "file.txt");
+            assert_eq!(desc.as_deref(), Some("an example file"));
+        }
+        other => panic!("unexpected variant: {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn tagged_enum_unit_variant_test() {
+    let form_data = MultipartFormBuilder::new()
+        .text("kind", "none")
+        .build("my_boundary");
+
+    let multipart = Multipart::with_reader(form_data.as_bytes(), "my_boundary");
+    let form = MultipartForm::with_multipart(multipart).await.unwrap();
+    let result = Upload::from_multipart(&form, Default::default()).unwrap();
+
+    assert!(matches!(result, Upload::Empty));
+}
+
+#[tokio::test]
+async fn tagged_enum_errors_test() {
+    let form_data = MultipartFormBuilder::new()
+        .text("kind", "video")
+        .build("my_boundary");
+
+    let multipart = Multipart::with_reader(form_data.as_bytes(), "my_boundary");
+    let form = MultipartForm::with_multipart(multipart).await.unwrap();
+    let err = Upload::from_multipart(&form, Default::default()).unwrap_err();
+
+    assert_eq!(
+        err.to_string(),
+        "`kind` form field is invalid: unknown variant `video`, expected one of `url`, `file`, `none`"
+    );
+
+    let form_data = MultipartFormBuilder::new()
+        .text("url", "https://example.com/image.png")
+        .build("my_boundary");
+
+    let multipart = Multipart::with_reader(form_data.as_bytes(), "my_boundary");
+    let form = MultipartForm::with_multipart(multipart).await.unwrap();
+    let err = Upload::from_multipart(&form, Default::default()).unwrap_err();
+
+    assert_eq!(err.to_string(), "`kind` form field was not found");
+}
+
+#[tokio::test]
+async fn tagged_enum_rename_all_test() {
+    let form_data = MultipartFormBuilder::new()
+        .text("kind", "imageUrl")
+        .text("imageUrl", "https://example.com/image.png")
+        .build("my_boundary");
+
+    let multipart = Multipart::with_reader(form_data.as_bytes(), "my_boundary");
+    let form = MultipartForm::with_multipart(multipart).await.unwrap();
+    let result = Link::from_multipart(&form, Default::default()).unwrap();
+
+    match result {
+        Link::ImageUrl { image_url } => assert_eq!(image_url, "https://example.com/image.png"),
+        other => panic!("unexpected variant: {other:?}"),
+    }
+
+    let form_data = MultipartFormBuilder::new()
+        .text("kind", "pageUrl")
+        .text("pageUrl", "https://example.com")
+        .build("my_boundary");
+
+    let multipart = Multipart::with_reader(form_data.as_bytes(), "my_boundary");
+    let form = MultipartForm::with_multipart(multipart).await.unwrap();
+    let result = Link::from_multipart(&form, Default::default()).unwrap();
+
+    match result {
+        Link::PageUrl { page_url } => assert_eq!(page_url, "https://example.com"),
+        other => panic!("unexpected variant: {other:?}"),
+    }
+}
@@ -0,0 +1,101 @@
+use multer_derive::{
+    helpers::MultipartFormBuilder, multer::Multipart, FieldErrorKind, FromMultipart, MultipartForm,
+};
+
+#[derive(Debug, PartialEq, FromMultipart)]
+struct Email(String);
+
+#[derive(Debug, PartialEq, FromMultipart)]
+struct Pair(String, u8);
+
+#[derive(Debug, PartialEq, FromMultipart)]
+struct NamedPair(
+    #[multer(rename = "name")] String,
+    #[multer(rename = "age")] u8,
+);
+
+#[derive(FromMultipart)]
+struct Contact {
+    name: String,
+    email: Email,
+}
+
+async fn get_form() -> MultipartForm {
+    let form_data = MultipartFormBuilder::new()
+        .text("name", "John Smith")
+        .text("age", "25")
+        .text("email", "john@example.com")
+        .build("my_boundary");
+
+    let multipart = Multipart::with_reader(form_data.as_bytes(), "my_boundary");
+    MultipartForm::with_multipart(multipart).await.unwrap()
+}
+
+#[tokio::test]
+async fn newtype_struct_test() {
+    let form = get_form().await;
+    let contact = Contact::from_multipart(&form, Default::default()).unwrap();
+
+    assert_eq!(contact.name, "John Smith");
+    assert_eq!(contact.email, Email("john@example.com".to_owned()));
+}
+
+#[tokio::test]
+async fn tuple_struct_by_index_test() {
+    let form = get_form().await;
+    let pair = Pair::from_multipart(&form, Default::default()).unwrap();
+
+    assert_eq!(pair, Pair("John Smith".to_owned(), 25));
+}
+
+#[tokio::test]
+async fn tuple_struct_rename_test() {
+    let form = get_form().await;
+    let pair = NamedPair::from_multipart(&form, Default::default()).unwrap();
+
+    assert_eq!(pair, NamedPair("John Smith".to_owned(), 25));
+}
+
+#[derive(FromMultipart)]
+struct Shape {
+    name: String,
+    #[multer(nested)]
+    origin: Pair,
+}
+
+#[tokio::test]
+async fn nested_tuple_struct_test() {
+    let form_data = MultipartFormBuilder::new()
+        .text("name", "Square")
+        .text("origin[0]", "top")
+        .text("origin[1]", "10")
+        .build("my_boundary");
+
+    let multipart = Multipart::with_reader(form_data.as_bytes(), "my_boundary");
+    let form = MultipartForm::with_multipart(multipart).await.unwrap();
+    let shape = Shape::from_multipart(&form, Default::default()).unwrap();
+
+    assert_eq!(shape.name, "Square");
+    assert_eq!(shape.origin, Pair("top".to_owned(), 10));
+}
+
+#[tokio::test]
+async fn nested_tuple_struct_error_test() {
+    let form_data = MultipartFormBuilder::new()
+        .text("name", "Square")
+        .text("origin[0]", "top")
+        .text("origin[1]", "ten")
+        .build("my_boundary");
+
+    let multipart = Multipart::with_reader(form_data.as_bytes(), "my_boundary");
+    let form = MultipartForm::with_multipart(multipart).await.unwrap();
+    let err = Shape::from_multipart(&form, Default::default())
+        .err()
+        .unwrap();
+    let field_error = err.as_field_error().unwrap();
+
+    assert_eq!(field_error.kind(), FieldErrorKind::Parse);
+    assert_eq!(field_error.name(), Some("1"));
+    assert_eq!(field_error.path(), Some("origin[1]"));
+    assert_eq!(field_error.index(), Some(2));
+}
@@ -0,0 +1,199 @@
+use multer_derive::{
+    helpers::MultipartFormBuilder, multer::Multipart, ConstraintValue, FieldErrorKind,
+    FromMultipart, MultipartForm,
+};
+
+/// A pattern that matches lowercase ascii words, like a `regex::Regex`.
+struct Lowercase;
+
+impl Lowercase {
+    fn is_match(&self, value: &str) -> bool {
+        !value.is_empty() && value.chars().all(|c| c.is_ascii_lowercase())
+    }
+}
+
+static USERNAME: Lowercase = Lowercase;
+
+#[derive(Debug, FromMultipart)]
+struct Signup {
+    #[multer(validate(length(min = 1, max = 10), regex(path = USERNAME)))]
+    username: String,
+
+    #[multer(validate(email))]
+    email: String,
+
+    #[multer(validate(range(min = 18, max = 99)))]
+    age: u8,
+
+    #[multer(default, validate(url))]
+    website: Option<String>,
+}
+
+#[derive(Debug, FromMultipart)]
+#[multer(collect_errors)]
+struct CollectedSignup {
+    #[multer(validate(length(min = 1, max = 10), regex(path = USERNAME)))]
+    username: String,
+
+    #[multer(validate(email))]
+    email: String,
+
+    #[multer(validate(range(min = 18)))]
+    age: u8,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, FromMultipart)]
+struct Profile {
+    #[multer(validate(regex = "^[a-z]+$"))]
+    username: String,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, FromMultipart)]
+struct Admin {
+    #[multer(validate(regex = "admin"))]
+    username: String,
+}
+
+async fn build_form(username: &str, email: &str, age: &str) -> MultipartForm {
+    let form_data = MultipartFormBuilder::new()
+        .text("username", username)
+        .text("email", email)
+        .text("age", age)
+        .build("my_boundary");
+
+    let multipart = Multipart::with_reader(form_data.as_bytes(), "my_boundary");
+    MultipartForm::with_multipart(multipart).await.unwrap()
+}
+
+#[tokio::test]
+async fn validate_ok_test() {
+    let form = build_form("john", "john@example.com", "30").await;
+    let signup = Signup::from_multipart(&form, Default::default()).unwrap();
+
+    assert_eq!(signup.username, "john");
+    assert_eq!(signup.email, "john@example.com");
+    assert_eq!(signup.age, 30);
+    assert_eq!(signup.website, None);
+}
+
+#[tokio::test]
+async fn validate_length_test() {
+    let form = build_form("johnjohnjohn", "john@example.com", "30").await;
+    let err = Signup::from_multipart(&form, Default::default())
+        .err()
+        .unwrap();
+    let field_error = err.as_field_error().unwrap();
+
+    assert_eq!(field_error.kind(), FieldErrorKind::Validation);
+    assert_eq!(field_error.name(), Some("username"));
+    assert_eq!(field_error.path(), Some("username"));
+    assert_eq!(
+        field_error.constraints().collect::<Vec<_>>(),
+        [
+            ("min", &ConstraintValue::UInt(1)),
+            ("max", &ConstraintValue::UInt(10))
+        ]
+    );
+    assert_eq!(
+        err.to_string(),
+        "`username` form field is invalid: length must be between 1 and 10"
+    );
+}
+
+#[tokio::test]
+async fn validate_regex_test() {
+    let form = build_form("John", "john@example.com", "30").await;
+    let err = Signup::from_multipart(&form, Default::default())
+        .err()
+        .unwrap();
+
+    assert_eq!(
+        err.to_string(),
+        "`username` form field is invalid: does not match the expected pattern"
+    );
+}
+
+#[tokio::test]
+async fn validate_regex_pattern_test() {
+    let form = build_form("john", "john@example.com", "30").await;
+    assert!(Profile::from_multipart(&form, Default::default()).is_ok());
+
+    let form = build_form("John", "john@example.com", "30").await;
+    let err = Profile::from_multipart(&form, Default::default())
+        .err()
+        .unwrap();
+
+    assert_eq!(
+        err.to_string(),
+        "`username` form field is invalid: does not match the expected pattern"
+    );
+
+    // A pattern which is also a valid path
+    let form = build_form("the_admin", "john@example.com", "30").await;
+    assert!(Admin::from_multipart(&form, Default::default()).is_ok());
+
+    let form = build_form("john", "john@example.com", "30").await;
+    assert!(Admin::from_multipart(&form, Default::default()).is_err());
+}
+
+#[tokio::test]
+async fn validate_range_test() {
+    let form = build_form("john", "john@example.com", "17").await;
+    let err = Signup::from_multipart(&form, Default::default())
+        .err()
+        .unwrap();
+
+    assert_eq!(
+        err.to_string(),
+        "`age` form field is invalid: must be between 18 and 99"
+    );
+}
+
+#[tokio::test]
+async fn validate_option_test() {
+    let form_data = MultipartFormBuilder::new()
+        .text("username", "john")
+        .text("email", "john@example.com")
+        .text("age", "30")
+        .text("website", "example.com")
+        .build("my_boundary");
+
+    let multipart = Multipart::with_reader(form_data.as_bytes(), "my_boundary");
+    let form = MultipartForm::with_multipart(multipart).await.unwrap();
+    let err = Signup::from_multipart(&form, Default::default())
+        .err()
+        .unwrap();
+
+    assert_eq!(
+        err.to_string(),
+        "`website` form field is invalid: must be an url"
+    );
+}
+
+#[tokio::test]
+async fn validate_collect_errors_test() {
+    let form = build_form("John Smith Jr", "john", "17").await;
+    let err = CollectedSignup::from_multipart(&form, Default::default())
+        .err()
+        .unwrap();
+    let errors = err.as_errors().unwrap();
+
+    assert_eq!(errors.get("username").count(), 2);
+    assert_eq!(errors.get("email").count(), 1);
+    assert_eq!(errors.get("age").count(), 1);
+    assert!(errors
+        .iter()
+        .all(|error| error.kind() == FieldErrorKind::Validation));
+}
+
+#[tokio::test]
+async fn validate_collect_errors_ok_test() {
+    let form = build_form("john", "john@example.com", "18").await;
+    let signup = CollectedSignup::from_multipart(&form, Default::default()).unwrap();
+
+    assert_eq!(signup.username, "john");
+    assert_eq!(signup.email, "john@example.com");
+    assert_eq!(signup.age, 18);
+}
@@ -0,0 +1,136 @@
+use crate::common::build_form;
+use multer_derive::{
+    helpers::MultipartFormBuilder, validate, ConstraintValue, FieldErrorKind, FormContext,
+    FromMultipart, Notation,
+};
+use validator::Validate;
+
+#[derive(Debug, FromMultipart, Validate)]
+struct Address {
+    #[validate(length(min = 1, message = "must not be empty"))]
+    street: String,
+}
+
+#[derive(Debug, FromMultipart, Validate)]
+struct Item {
+    #[validate(length(max = 4))]
+    name: String,
+}
+
+#[derive(Debug, FromMultipart, Validate)]
+#[multer(notation = "dots")]
+struct Order {
+    #[multer(rename = "qty")]
+    #[validate(range(min = 1))]
+    quantity: u32,
+
+    #[multer(nested)]
+    #[validate(nested)]
+    address: Address,
+
+    #[multer(indexed)]
+    #[validate(nested)]
+    items: Vec<Item>,
+}
+
+#[tokio::test]
+async fn validator_ok_test() {
+    let form = build_form(
+        MultipartFormBuilder::new()
+            .text("qty", "2")
+            .text("address.street", "Main St")
+            .text("items.0.name", "pen"),
+    )
+    .await;
+
+    let order = Order::from_multipart(&form, Default::default()).unwrap();
+    assert_eq!(order.quantity, 2);
+    assert_eq!(order.items[0].name, "pen");
+}
+
+#[tokio::test]
+async fn validator_renamed_field_test() {
+    let form = build_form(
+        MultipartFormBuilder::new()
+            .text("qty", "0")
+            .text("address.street", "Main St"),
+    )
+    .await;
+
+    let err = Order::from_multipart(&form, Default::default())
+        .err()
+        .unwrap();
+    let errors = err.as_errors().unwrap();
+
+    assert_eq!(errors.len(), 1);
+    let error = errors.iter().next().unwrap();
+    assert_eq!(error.kind(), FieldErrorKind::Validation);
+    assert_eq!(error.name(), Some("qty"));
+    assert_eq!(error.path(), Some("qty"));
+    assert_eq!(
+        error.constraints().collect::<Vec<_>>(),
+        [("min", &ConstraintValue::UInt(1))]
+    );
+}
+
+#[tokio::test]
+async fn validator_nested_field_test() {
+    let form = build_form(
+        MultipartFormBuilder::new()
+            .text("qty", "1")
+            .text("address.street", "")
+            .text("items.0.name", "pencil"),
+    )
+    .await;
+
+    let err = Order::from_multipart(&form, Default::default())
+        .err()
+        .unwrap();
+    let error = err.as_errors().unwrap().iter().next().unwrap();
+
+    assert_eq!(error.name(), Some("street"));
+    assert_eq!(error.path(), Some("address.street"));
+    assert_eq!(
+        error.to_string(),
+        "`address.street` form field is invalid: must not be empty"
+    );
+}
+
+#[test]
+fn validator_errors_test() {
+    let order = Order {
+        quantity: 1,
+        address: Address {
+            street: String::new(),
+        },
+        items: vec![
+            Item {
+                name: "pen".to_owned(),
+            },
+            Item {
+                name: "pencil".to_owned(),
+            },
+        ],
+    };
+
+    let ctx = FormContext {
+        prefix: Some("order"),
+        notation: Notation::Brackets,
+        ..Default::default()
+    };
+
+    let errors = validate::validator_errors(&order.validate().unwrap_err(), &ctx);
+    let by_path = errors.by_path();
+
+    assert_eq!(
+        by_path.keys().copied().collect::<Vec<_>>(),
+        ["order[address][street]", "order[items][1][name]"]
+    );
+    assert_eq!(by_path["order[items][1][name]"][0].name(), Some("name"));
+    assert_eq!(
+        by_path["order[items][1][name]"][0]
+            .constraints()
+            .collect::<Vec<_>>(),
+        [("max", &ConstraintValue::UInt(4))]
+    );
+}
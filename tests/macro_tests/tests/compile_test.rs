@@ -4,4 +4,4 @@ fn ui() {
     let t = trybuild::TestCases::new();
     t.compile_fail("tests/fail/**/*.rs");
     t.pass("tests/pass/**/*.rs");
-}
\ No newline at end of file
+}
@@ -0,0 +1,9 @@
+use multer_derive::FromMultipart;
+
+#[derive(FromMultipart)]
+struct MyStruct {
+    #[multer(collect_errors)]
+    text: String,
+}
+
+fn main() {}
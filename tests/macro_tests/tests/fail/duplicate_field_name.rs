@@ -0,0 +1,11 @@
+use multer_derive::FromMultipart;
+
+#[derive(FromMultipart)]
+struct MyStruct {
+    text: String,
+
+    #[multer(rename = "text")]
+    other_text: String,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use multer_derive::FromMultipart;
+
+#[derive(FromMultipart)]
+struct MyStruct {
+    #[multer(rename = "text")]
+    #[multer(rename = "other_text")]
+    text: String,
+    number: u32,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use multer_derive::FromMultipart;
+
+#[derive(FromMultipart)]
+struct MyStruct {
+    #[multer(rename = "text", rename = "other_text")]
+    text: String,
+    number: u32,
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use multer_derive::FromMultipart;
+
+#[derive(FromMultipart)]
+enum Upload {
+    Url { url: String },
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+use multer_derive::FromMultipart;
+
+#[derive(FromMultipart)]
+struct MyStruct {
+    text: String,
+
+    #[multer(flatten, rename = "page")]
+    pagination: Pagination,
+}
+
+#[derive(FromMultipart)]
+struct Pagination {
+    page: u32,
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+use multer_derive::{FromMultipart, FromMultipartAsync};
+
+#[derive(FromMultipartAsync)]
+struct MyStruct {
+    text: String,
+
+    #[multer(nested)]
+    address: Address,
+}
+
+#[derive(FromMultipart)]
+struct Address {
+    street: String,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use multer_derive::FromMultipartField;
+
+#[derive(FromMultipartField)]
+#[multer(rename_all = "lowercase", collect_errors)]
+enum Plan {
+    Free,
+    Pro,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use multer_derive::FromMultipartField;
+
+#[derive(FromMultipartField)]
+enum Plan {
+    Free,
+    Custom(String),
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use multer_derive::FromMultipartField;
+
+#[derive(FromMultipartField)]
+enum Plan {
+    Free,
+    #[multer(rename = "pro", skip)]
+    Pro,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use multer_derive::FromMultipart;
+
+#[derive(FromMultipart)]
+struct MyStruct {
+    #[multer(limit = "5TB")]
+    text: String,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use multer_derive::FromMultipart;
+
+#[derive(FromMultipart)]
+struct MyStruct {
+    text: String,
+
+    #[multer(skip, rename = "count")]
+    count: u32,
+}
+
+fn main() {}
@@ -0,0 +1,6 @@
+use multer_derive::FromMultipart;
+
+#[derive(FromMultipart)]
+struct Tags(#[multer(indexed)] Vec<String>);
+
+fn main() {}
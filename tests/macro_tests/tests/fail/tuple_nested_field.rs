@@ -0,0 +1,6 @@
+use multer_derive::FromMultipart;
+
+#[derive(FromMultipart)]
+struct Pair(#[multer(rename = "a", nested)] String, u8);
+
+fn main() {}
@@ -0,0 +1,9 @@
+use multer_derive::FromMultipart;
+
+#[derive(FromMultipart)]
+#[multer(rename_all = "camelcase")]
+struct MyStruct {
+    first_name: String,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use multer_derive::FromMultipart;
+
+#[derive(FromMultipart)]
+struct MyStruct {
+    #[multer(validate(length(min = 1), phone))]
+    text: String,
+}
+
+fn main() {}
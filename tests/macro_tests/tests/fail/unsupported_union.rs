@@ -0,0 +1,9 @@
+use multer_derive::FromMultipart;
+
+#[derive(FromMultipart)]
+union MyUnion {
+    number: u32,
+    float: f32,
+}
+
+fn main() {}
@@ -0,0 +1,6 @@
+use multer_derive::FromMultipart;
+
+#[derive(FromMultipart)]
+struct MyStruct;
+
+fn main() {}
@@ -0,0 +1,9 @@
+use multer_derive::FromMultipart;
+
+#[derive(FromMultipart)]
+struct MyStruct {
+    #[multer(validate(regex = "^[a-z]+$"))]
+    username: String,
+}
+
+fn main() {}
@@ -0,0 +1,39 @@
+use multer_derive::FromMultipart;
+
+#[derive(FromMultipart)]
+struct Address {
+    street: String,
+}
+
+#[derive(FromMultipart)]
+#[multer(collect_errors, default)]
+struct MyStruct {
+    text: String,
+
+    #[multer(nested)]
+    address: Address,
+
+    #[multer(skip)]
+    count: u32,
+}
+
+impl Default for MyStruct {
+    fn default() -> Self {
+        MyStruct {
+            text: String::new(),
+            address: Address {
+                street: String::new(),
+            },
+            count: 0,
+        }
+    }
+}
+
+#[derive(FromMultipart)]
+#[multer(tag = "kind", collect_errors)]
+enum Source {
+    Url { url: String, title: String },
+    None,
+}
+
+fn main() {}
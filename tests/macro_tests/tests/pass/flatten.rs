@@ -0,0 +1,17 @@
+use multer_derive::FromMultipart;
+
+#[derive(FromMultipart)]
+struct MyStruct {
+    text: String,
+
+    #[multer(flatten)]
+    pagination: Pagination,
+}
+
+#[derive(FromMultipart)]
+struct Pagination {
+    page: u32,
+    per_page: u32,
+}
+
+fn main() {}
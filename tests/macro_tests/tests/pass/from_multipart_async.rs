@@ -0,0 +1,15 @@
+use multer_derive::{FormFile, FromMultipartAsync};
+
+#[derive(FromMultipartAsync)]
+struct Upload<T> {
+    name: String,
+    value: T,
+    files: Vec<FormFile>,
+}
+
+fn assert_send<F: Send>(_: F) {}
+
+fn main() {
+    let multipart = multer_derive::multer::Multipart::with_reader(&b""[..], "boundary");
+    assert_send(Upload::<u32>::from_multipart_async(multipart));
+}
@@ -0,0 +1,16 @@
+use multer_derive::{FromMultipart, FromMultipartField};
+
+#[derive(FromMultipartField)]
+enum Plan {
+    Free,
+    #[multer(rename = "pro")]
+    Pro,
+}
+
+#[derive(FromMultipart)]
+struct MyStruct {
+    plan: Plan,
+    plans: Vec<Plan>,
+}
+
+fn main() {}
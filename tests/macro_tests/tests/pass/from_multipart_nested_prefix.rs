@@ -0,0 +1,20 @@
+use multer_derive::FromMultipart;
+
+#[derive(FromMultipart)]
+#[multer(notation = "dots")]
+struct MyStruct {
+    text: String,
+
+    #[multer(nested)]
+    nested: Nested,
+
+    #[multer(prefix = "other")]
+    other_nested: Nested,
+}
+
+#[derive(FromMultipart)]
+struct Nested {
+    value: String,
+}
+
+fn main() {}
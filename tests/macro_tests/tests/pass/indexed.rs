@@ -0,0 +1,17 @@
+use multer_derive::FromMultipart;
+
+#[derive(FromMultipart)]
+struct MyStruct {
+    #[multer(indexed)]
+    items: Vec<Item>,
+
+    #[multer(indexed, default)]
+    tags: Vec<String>,
+}
+
+#[derive(FromMultipart)]
+struct Item {
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,30 @@
+use multer_derive::FromMultipart;
+
+#[derive(FromMultipart)]
+struct MyStruct {
+    #[multer(default)]
+    text: String,
+
+    #[multer(default = "default_number")]
+    number: u32,
+}
+
+fn default_number() -> u32 {
+    10
+}
+
+#[derive(Default, FromMultipart)]
+#[multer(default)]
+struct Other {
+    text: String,
+    number: u32,
+}
+
+#[derive(Default, FromMultipart)]
+#[multer(default)]
+struct Pair(String, #[multer(rename = "number")] u32);
+
+#[derive(FromMultipart)]
+struct Wrapper(#[multer(default)] u32);
+
+fn main() {}
@@ -0,0 +1,20 @@
+use multer_derive::FromMultipart;
+
+struct Cache;
+
+#[derive(FromMultipart)]
+struct MyStruct {
+    text: String,
+
+    #[multer(skip)]
+    count: u32,
+
+    #[multer(skip, default = "new_cache")]
+    cache: Cache,
+}
+
+fn new_cache() -> Cache {
+    Cache
+}
+
+fn main() {}
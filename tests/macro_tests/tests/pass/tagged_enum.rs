@@ -0,0 +1,11 @@
+use multer_derive::{FormFile, FromMultipart};
+
+#[derive(FromMultipart)]
+#[multer(tag = "kind")]
+enum Upload {
+    Url { url: String },
+    File { file: FormFile },
+    Empty,
+}
+
+fn main() {}
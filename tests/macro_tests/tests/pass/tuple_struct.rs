@@ -0,0 +1,15 @@
+use multer_derive::FromMultipart;
+
+#[derive(FromMultipart)]
+struct Email(String);
+
+#[derive(FromMultipart)]
+struct Pair<A, B>(A, #[multer(rename = "second")] B);
+
+#[derive(FromMultipart)]
+struct MyStruct {
+    email: Email,
+    pair: Pair<String, u32>,
+}
+
+fn main() {}
@@ -0,0 +1,31 @@
+use multer_derive::FromMultipart;
+
+struct Lowercase;
+
+impl Lowercase {
+    fn is_match(&self, value: &str) -> bool {
+        value.chars().all(|c| c.is_ascii_lowercase())
+    }
+}
+
+static LOWERCASE: Lowercase = Lowercase;
+
+#[derive(FromMultipart)]
+struct MyStruct {
+    #[multer(validate(length(max = 80)))]
+    title: String,
+
+    #[multer(validate(range(min = -1.5, max = 1.5)))]
+    score: f32,
+
+    #[multer(validate(email))]
+    email: Option<String>,
+
+    #[multer(validate(regex(path = LOWERCASE)))]
+    username: String,
+
+    #[multer(indexed, validate(length(min = 1)))]
+    tags: Vec<String>,
+}
+
+fn main() {}